@@ -0,0 +1,151 @@
+//! Known puzzle answers, used to check that refactors keep the results.
+//!
+//! The file is a small TOML subset, one table per day:
+//!
+//! ```toml
+//! [day7]
+//! part1 = 6440
+//! part2 = "5905"
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Answers(BTreeMap<(u8, u8), String>);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AnswersError {
+    /// 1-based line number.
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Line {}: {}", self.line, self.reason)
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self, AnswersError> {
+        let mut answers = BTreeMap::new();
+        let mut day = None;
+
+        for (idx, line) in input.lines().enumerate() {
+            let err = |reason| AnswersError {
+                line: idx + 1,
+                reason,
+            };
+
+            let line = line.split_once('#').map_or(line, |(l, _)| l).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let num = header
+                    .strip_suffix(']')
+                    .and_then(|h| h.trim().strip_prefix("day"))
+                    .ok_or(err("Expected a [dayN] header"))?;
+                day = Some(num.parse::<u8>().map_err(|_| err("Invalid day number"))?);
+                continue;
+            }
+
+            let day = day.ok_or(err("Answer before any [dayN] header"))?;
+            let (key, value) = line.split_once('=').ok_or(err("Expected key = value"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                _ => return Err(err("Key must be part1 or part2")),
+            };
+
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(v) => v.strip_suffix('"').ok_or(err("Unterminated string"))?,
+                None => value,
+            };
+
+            if answers.insert((day, part), value.to_string()).is_some() {
+                return Err(err("Duplicate answer"));
+            }
+        }
+
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Check {
+        match self.get(day, part) {
+            None => Check::Missing,
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Answers, AnswersError, Check};
+
+    const SAMPLE: &str = r#"# answers for the samples
+[day7]
+part1 = 6440
+part2 = "5905"  # with jokers
+
+[ day15 ]
+part1 = "1320"
+"#;
+
+    #[test]
+    fn parse() {
+        let a = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(a.get(7, 1), Some("6440"));
+        assert_eq!(a.get(7, 2), Some("5905"));
+        assert_eq!(a.get(15, 1), Some("1320"));
+        assert_eq!(a.get(15, 2), None);
+
+        assert_eq!(
+            Answers::parse("part1 = 1"),
+            Err(AnswersError {
+                line: 1,
+                reason: "Answer before any [dayN] header"
+            })
+        );
+        assert_eq!(Answers::parse("[day1]\npart3 = 1").unwrap_err().line, 2);
+        assert_eq!(
+            Answers::parse("[day1]\npart1 = 1\npart1 = 2")
+                .unwrap_err()
+                .reason,
+            "Duplicate answer"
+        );
+        assert!(Answers::parse("[dayx]").is_err());
+        assert!(Answers::parse("[day1]\npart1 = \"1").is_err());
+    }
+
+    #[test]
+    fn check() {
+        let a = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(a.check(7, 1, "6440"), Check::Pass);
+        assert_eq!(
+            a.check(7, 2, "6440"),
+            Check::Fail {
+                expected: "5905".to_string()
+            }
+        );
+        assert_eq!(a.check(8, 1, "6"), Check::Missing);
+    }
+}
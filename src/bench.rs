@@ -0,0 +1,180 @@
+//! Repeated timing of each day's parse and solve steps.
+
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::Solution;
+
+/// Summary of a set of timing samples.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let var = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(var.sqrt()),
+        }
+    }
+}
+
+/// Timings of one day.
+///
+/// Every part parses the input again, so `part1` and `part2` include the time
+/// of `parse`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+    /// Median wall time of running both parts, parsing included.
+    pub total: Duration,
+}
+
+fn time_runs(runs: usize, mut f: impl FnMut()) -> Vec<Duration> {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect()
+}
+
+pub fn bench_day(day: u8, solution: &dyn Solution, input: &str, runs: usize) -> DayBench {
+    let parse = time_runs(runs, || solution.parse(black_box(input)));
+    let part1 = time_runs(runs, || {
+        let _ = black_box(solution.part1(black_box(input)));
+    });
+    let part2 = time_runs(runs, || {
+        let _ = black_box(solution.part2(black_box(input)));
+    });
+
+    let total = Stats::from_samples(
+        &part1
+            .iter()
+            .zip(&part2)
+            .map(|(a, b)| *a + *b)
+            .collect::<Vec<Duration>>(),
+    )
+    .median;
+
+    DayBench {
+        day,
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+        total,
+    }
+}
+
+fn micros(d: Duration) -> f64 {
+    d.as_secs_f64() * 1e6
+}
+
+/// Table of all days, times in uS, followed by the runtime of the whole year.
+pub struct Table<'a>(pub &'a [DayBench]);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>3}  {:<5} {:>12} {:>12} {:>12} {:>12}",
+            "Day", "Step", "min", "median", "mean", "stddev"
+        )?;
+
+        for r in self.0 {
+            let steps = [("parse", r.parse), ("part1", r.part1), ("part2", r.part2)];
+            for (idx, (step, stats)) in steps.iter().enumerate() {
+                let day = if idx == 0 {
+                    r.day.to_string()
+                } else {
+                    String::new()
+                };
+                writeln!(
+                    f,
+                    "{day:>3}  {step:<5} {:>12.1} {:>12.1} {:>12.1} {:>12.1}",
+                    micros(stats.min),
+                    micros(stats.median),
+                    micros(stats.mean),
+                    micros(stats.stddev),
+                )?;
+            }
+        }
+
+        let total = self.0.iter().map(|r| r.total).sum::<Duration>();
+        writeln!(f, "Total: {:.1} uS", micros(total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{bench_day, Stats, Table};
+    use crate::days::day1::Day1;
+
+    #[test]
+    fn stats() {
+        let ms = Duration::from_millis;
+
+        let s = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(8)]);
+        assert_eq!(s.min, ms(2));
+        assert_eq!(s.median, ms(5));
+        assert_eq!(s.mean, ms(5));
+        assert_eq!(s.stddev.as_micros(), 2236);
+
+        let s = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+        assert_eq!(s.median, ms(2));
+
+        assert_eq!(Stats::from_samples(&[]), Stats::default());
+    }
+
+    #[test]
+    fn bench() {
+        let r = bench_day(1, &Day1, "1abc2\n", 3);
+        assert_eq!(r.day, 1);
+
+        let t = Table(&[r]).to_string();
+        assert_eq!(t.lines().count(), 5);
+        assert!(t
+            .lines()
+            .nth(1)
+            .unwrap()
+            .trim_start()
+            .starts_with("1  parse"));
+        assert!(t.ends_with(" uS\n"));
+    }
+}
@@ -0,0 +1,560 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc2023::{
+    answers::{Answers, Check},
+    bench::{bench_day, Table},
+    days, input_dir, input_path, read_input, InputError, Solution,
+};
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input-dir <dir>] [--input <file|->]
+                      [--check [--answers <file>]]
+                      [--visualize [--delay <ms>] [--dump <file>]]
+    aoc bench <day|all> [--runs <n>] [--input-dir <dir>]
+    aoc report <day> [<arg>] [--input-dir <dir>] [--input <file|->]
+                     [--output <file>]
+    aoc list
+
+The input directory defaults to $AOC_INPUT_DIR, then the current directory.
+`--input -` reads a single day's input from stdin.
+`--check` compares every answer with the answers file, by default
+`answers.toml` in the input directory, and fails on a mismatch.
+`--visualize` animates a single day in the terminal, `--delay` milliseconds
+per frame (default 50), or writes all frames to the `--dump` file instead.
+`bench` times parsing on its own and every part with its parsing.
+`report` prints day specific details, `<arg>` picks which:
+    7: every hand ranked, `standard` or `jokers` for one set of rules, or
+       `<order>[:<wild>[:<hand size>]]` for others.
+    10: the loop drawn over the map, no `<arg>`.
+    14: `csv` for the load after every spin, or a spin number to show.
+    15: the boxes after every step, no `<arg>`.";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Days {
+    All,
+    One(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Default)]
+struct RunOpts {
+    part: Option<u8>,
+    input_dir: Option<PathBuf>,
+    input: Option<PathBuf>,
+    check: bool,
+    answers: Option<PathBuf>,
+    visualize: bool,
+    delay: Option<u64>,
+    dump: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        days: Days,
+        opts: RunOpts,
+    },
+    Bench {
+        days: Days,
+        runs: usize,
+        input_dir: Option<PathBuf>,
+    },
+    Report {
+        day: u8,
+        arg: Option<String>,
+        input_dir: Option<PathBuf>,
+        input: Option<PathBuf>,
+        output: Option<PathBuf>,
+    },
+    List,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let cmd = args.next().ok_or("Missing command")?;
+
+    match cmd.as_str() {
+        "list" => Ok(Command::List),
+        "run" => {
+            let mut days = None;
+            let mut opts = RunOpts::default();
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" | "-p" => {
+                        let value = args.next().ok_or("--part needs a value")?;
+                        opts.part = match value.as_str() {
+                            "1" => Some(1),
+                            "2" => Some(2),
+                            _ => return Err(format!("Invalid part: {value}")),
+                        };
+                    }
+                    "--input-dir" => {
+                        let value = args.next().ok_or("--input-dir needs a value")?;
+                        opts.input_dir = Some(PathBuf::from(value));
+                    }
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("--input needs a value")?;
+                        opts.input = Some(PathBuf::from(value));
+                    }
+                    "--check" => opts.check = true,
+                    "--answers" => {
+                        let value = args.next().ok_or("--answers needs a value")?;
+                        opts.answers = Some(PathBuf::from(value));
+                    }
+                    "--visualize" => opts.visualize = true,
+                    "--delay" => {
+                        let value = args.next().ok_or("--delay needs a value")?;
+                        let delay = value
+                            .parse()
+                            .map_err(|_| format!("Invalid delay: {value}"))?;
+                        opts.delay = Some(delay);
+                    }
+                    "--dump" => {
+                        let value = args.next().ok_or("--dump needs a value")?;
+                        opts.dump = Some(PathBuf::from(value));
+                    }
+                    "all" => days = Some(Days::All),
+                    day => {
+                        let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+                        days = Some(Days::One(day));
+                    }
+                }
+            }
+
+            let days = days.ok_or("Missing day")?;
+            if days == Days::All && opts.input.is_some() {
+                return Err("--input needs a single day".to_string());
+            }
+            if opts.answers.is_some() && !opts.check {
+                return Err("--answers needs --check".to_string());
+            }
+            if opts.visualize && (days == Days::All || opts.check) {
+                return Err("--visualize needs a single day, without --check".to_string());
+            }
+            if (opts.delay.is_some() || opts.dump.is_some()) && !opts.visualize {
+                return Err("--delay and --dump need --visualize".to_string());
+            }
+            Ok(Command::Run { days, opts })
+        }
+        "bench" => {
+            let mut days = None;
+            let mut runs = 10;
+            let mut input_dir = None;
+
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--runs" | "-n" => {
+                        let value = args.next().ok_or("--runs needs a value")?;
+                        runs = match value.parse() {
+                            Ok(n) if n > 0 => n,
+                            _ => return Err(format!("Invalid runs: {value}")),
+                        };
+                    }
+                    "--input-dir" => {
+                        let value = args.next().ok_or("--input-dir needs a value")?;
+                        input_dir = Some(PathBuf::from(value));
+                    }
+                    "all" => days = Some(Days::All),
+                    day => {
+                        let day = day.parse().map_err(|_| format!("Invalid day: {day}"))?;
+                        days = Some(Days::One(day));
+                    }
+                }
+            }
+
+            let days = days.ok_or("Missing day")?;
+            Ok(Command::Bench {
+                days,
+                runs,
+                input_dir,
+            })
+        }
+        "report" => {
+            let mut day = None;
+            let mut arg = None;
+            let mut input_dir = None;
+            let mut input = None;
+            let mut output = None;
+
+            while let Some(a) = args.next() {
+                match a.as_str() {
+                    "--input-dir" => {
+                        let value = args.next().ok_or("--input-dir needs a value")?;
+                        input_dir = Some(PathBuf::from(value));
+                    }
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("--input needs a value")?;
+                        input = Some(PathBuf::from(value));
+                    }
+                    "--output" | "-o" => {
+                        let value = args.next().ok_or("--output needs a value")?;
+                        output = Some(PathBuf::from(value));
+                    }
+                    value if day.is_none() => {
+                        day = Some(value.parse().map_err(|_| format!("Invalid day: {value}"))?);
+                    }
+                    value if arg.is_none() => arg = Some(value.to_string()),
+                    value => return Err(format!("Unexpected argument: {value}")),
+                }
+            }
+
+            Ok(Command::Report {
+                day: day.ok_or("Missing day")?,
+                arg,
+                input_dir,
+                input,
+                output,
+            })
+        }
+        e => Err(format!("Unknown command: {e}")),
+    }
+}
+
+/// Run the requested parts, returns `false` when a part fails or a checked
+/// answer is wrong.
+fn run_day(
+    day: u8,
+    solution: &dyn Solution,
+    part: Option<u8>,
+    path: &Path,
+    answers: Option<&Answers>,
+) -> Result<bool, InputError> {
+    println!("Day {day}");
+    let input = read_input(path)?;
+    let mut ok = true;
+
+    for p in [1, 2] {
+        if part.is_some() && part != Some(p) {
+            continue;
+        }
+
+        let start = Instant::now();
+        let answer = if p == 1 {
+            solution.part1(&input)
+        } else {
+            solution.part2(&input)
+        };
+        let elapsed = start.elapsed().as_micros();
+
+        let answer = match answer {
+            Ok(answer) => answer,
+            Err(e) => {
+                println!("Part{p}: ERROR {e}");
+                ok = false;
+                continue;
+            }
+        };
+
+        match answers.map(|a| a.check(day, p, &answer)) {
+            None => println!("Part{p}: {answer}, {elapsed} uS"),
+            Some(Check::Pass) => println!("Part{p}: {answer}, {elapsed} uS PASS"),
+            Some(Check::Missing) => println!("Part{p}: {answer}, {elapsed} uS MISSING"),
+            Some(Check::Fail { expected }) => {
+                println!("Part{p}: {answer}, {elapsed} uS FAIL expected {expected}");
+                ok = false;
+            }
+        }
+    }
+
+    Ok(ok)
+}
+
+/// Play the animation of a day in the terminal, or write it to `dump`.
+fn visualize(
+    day: u8,
+    solution: &dyn Solution,
+    path: &Path,
+    delay: Duration,
+    dump: Option<&Path>,
+) -> Result<(), String> {
+    let input = read_input(path).map_err(|e| e.to_string())?;
+    let frames = solution
+        .frames(&input)
+        .ok_or(format!("Day {day} has no visualization"))??;
+
+    if let Some(dump) = dump {
+        return fs::write(dump, frames.join("\n")).map_err(|e| format!("{}: {e}", dump.display()));
+    }
+
+    let mut out = io::stdout().lock();
+    for frame in &frames {
+        write!(out, "\x1b[H\x1b[2J{frame}")
+            .and_then(|_| out.flush())
+            .map_err(|e| e.to_string())?;
+        thread::sleep(delay);
+    }
+
+    Ok(())
+}
+
+/// Print the report of a day, or write it to `output`.
+fn report(
+    day: u8,
+    arg: Option<&str>,
+    dir: Option<&Path>,
+    input: Option<&Path>,
+    output: Option<&Path>,
+) -> Result<(), String> {
+    let solution = days::get(day).ok_or(format!("Day {day} is not solved"))?;
+    let path = input.map_or_else(|| input_path(&input_dir(dir), day), Path::to_path_buf);
+    let input = read_input(path).map_err(|e| e.to_string())?;
+
+    let text = solution
+        .report(&input, arg)
+        .ok_or(format!("Day {day} has no report"))??;
+
+    match output {
+        Some(output) => fs::write(output, text).map_err(|e| format!("{}: {e}", output.display())),
+        None => {
+            print!("{text}");
+            Ok(())
+        }
+    }
+}
+
+fn load_answers(path: &Path) -> Result<Answers, String> {
+    let data = read_input(path).map_err(|e| e.to_string())?;
+    Answers::parse(&data).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn select(days: Days) -> Option<Vec<(u8, &'static dyn Solution)>> {
+    match days {
+        Days::All => Some(days::DAYS.to_vec()),
+        Days::One(day) => {
+            let Some(solution) = days::get(day) else {
+                eprintln!("Day {day} is not solved");
+                return None;
+            };
+            Some(vec![(day, solution)])
+        }
+    }
+}
+
+fn bench(days: Days, runs: usize, dir: Option<&Path>) -> ExitCode {
+    let dir = input_dir(dir);
+    let Some(selected) = select(days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut results = Vec::new();
+    let mut failed = false;
+    for (day, solution) in selected {
+        match read_input(input_path(&dir, day)) {
+            Ok(input) => results.push(bench_day(day, solution, &input, runs)),
+            Err(e) => {
+                eprintln!("{e}");
+                failed = true;
+            }
+        }
+    }
+
+    print!("{}", Table(&results));
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run(days: Days, opts: RunOpts) -> ExitCode {
+    let dir = input_dir(opts.input_dir.as_deref());
+
+    let answers = if opts.check {
+        let path = opts.answers.unwrap_or_else(|| dir.join("answers.toml"));
+        match load_answers(&path) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+
+    let Some(selected) = select(days) else {
+        return ExitCode::FAILURE;
+    };
+
+    if opts.visualize {
+        let (day, solution) = selected[0];
+        let path = opts.input.unwrap_or_else(|| input_path(&dir, day));
+        let delay = Duration::from_millis(opts.delay.unwrap_or(50));
+        return match visualize(day, solution, &path, delay, opts.dump.as_deref()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let mut failed = false;
+    for (day, solution) in selected {
+        let path = match &opts.input {
+            Some(input) => input.clone(),
+            None => input_path(&dir, day),
+        };
+        match run_day(day, solution, opts.part, &path, answers.as_ref()) {
+            Ok(ok) => failed |= !ok,
+            Err(e) => {
+                eprintln!("{e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    let cmd = match parse_args(std::env::args().skip(1)) {
+        Ok(cmd) => cmd,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match cmd {
+        Command::List => {
+            for (day, _) in days::DAYS {
+                println!("{day}");
+            }
+            ExitCode::SUCCESS
+        }
+        Command::Run { days, opts } => run(days, opts),
+        Command::Bench {
+            days,
+            runs,
+            input_dir,
+        } => bench(days, runs, input_dir.as_deref()),
+        Command::Report {
+            day,
+            arg,
+            input_dir,
+            input,
+            output,
+        } => match report(
+            day,
+            arg.as_deref(),
+            input_dir.as_deref(),
+            input.as_deref(),
+            output.as_deref(),
+        ) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::{parse_args, Command, Days, RunOpts};
+
+    fn args(input: &str) -> impl Iterator<Item = String> + '_ {
+        input.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn process_args() {
+        assert_eq!(
+            parse_args(args("run 7 --part 2")),
+            Ok(Command::Run {
+                days: Days::One(7),
+                opts: RunOpts {
+                    part: Some(2),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse_args(args("run all")),
+            Ok(Command::Run {
+                days: Days::All,
+                opts: RunOpts::default(),
+            })
+        );
+        assert_eq!(
+            parse_args(args("run 5 --input-dir inputs -i -")),
+            Ok(Command::Run {
+                days: Days::One(5),
+                opts: RunOpts {
+                    input_dir: Some(PathBuf::from("inputs")),
+                    input: Some(PathBuf::from("-")),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse_args(args("run all --check --answers a.toml")),
+            Ok(Command::Run {
+                days: Days::All,
+                opts: RunOpts {
+                    check: true,
+                    answers: Some(PathBuf::from("a.toml")),
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(
+            parse_args(args("bench all -n 5")),
+            Ok(Command::Bench {
+                days: Days::All,
+                runs: 5,
+                input_dir: None,
+            })
+        );
+        assert!(parse_args(args("bench 7 --runs 0")).is_err());
+        assert!(parse_args(args("run all --input -")).is_err());
+        assert!(parse_args(args("run all --answers a.toml")).is_err());
+        assert_eq!(
+            parse_args(args("run 16 --visualize --delay 20 --dump beams.txt")),
+            Ok(Command::Run {
+                days: Days::One(16),
+                opts: RunOpts {
+                    visualize: true,
+                    delay: Some(20),
+                    dump: Some(PathBuf::from("beams.txt")),
+                    ..Default::default()
+                },
+            })
+        );
+        assert!(parse_args(args("run all --visualize")).is_err());
+        assert!(parse_args(args("run 16 --delay 20")).is_err());
+        assert!(parse_args(args("run 16 --visualize --delay fast")).is_err());
+        assert_eq!(
+            parse_args(args("report 14 1000 -o board.txt")),
+            Ok(Command::Report {
+                day: 14,
+                arg: Some("1000".to_string()),
+                input_dir: None,
+                input: None,
+                output: Some(PathBuf::from("board.txt")),
+            })
+        );
+        assert!(parse_args(args("report")).is_err());
+        assert!(parse_args(args("report all")).is_err());
+        assert!(parse_args(args("report 14 csv 3")).is_err());
+        assert_eq!(parse_args(args("list")), Ok(Command::List));
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("run 7 --part 3")).is_err());
+        assert!(parse_args(args("fly 7")).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+//! Where a repeatedly applied step starts going round in circles.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// The states repeat after the first `mu`, every `lambda` steps.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Cycle {
+    pub mu: usize,
+    pub lambda: usize,
+}
+
+impl Cycle {
+    /// Index among the first `mu + lambda` states of the state after `n` steps.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.mu {
+            n
+        } else {
+            self.mu + (n - self.mu) % self.lambda
+        }
+    }
+}
+
+/// Apply `step` from `start` until a state comes back.
+///
+/// Every state is kept in a map to the step it was first seen at, so this
+/// needs no warm-up and stops at the first repeat. Also returns the
+/// `mu + lambda` distinct states, index them with [`Cycle::index`].
+pub fn find_cycle<T: Clone + Hash + Eq>(
+    start: T,
+    mut step: impl FnMut(&T) -> T,
+) -> (Cycle, Vec<T>) {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = start;
+
+    loop {
+        if let Some(&mu) = seen.get(&state) {
+            let lambda = states.len() - mu;
+            return (Cycle { mu, lambda }, states);
+        }
+        seen.insert(state.clone(), states.len());
+
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::{find_cycle, Cycle};
+
+    #[test]
+    fn cycles() {
+        // 0 1 2 3 4 5 2 3 ...
+        let (cycle, states) = find_cycle(0, |n| if *n < 5 { n + 1 } else { 2 });
+        assert_eq!(cycle, Cycle { mu: 2, lambda: 4 });
+        assert_eq!(states, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(states[cycle.index(1)], 1);
+        assert_eq!(states[cycle.index(6)], 2);
+        assert_eq!(states[cycle.index(1_000_000_001)], 5);
+
+        let (cycle, _) = find_cycle(0, |n| (n + 1) % 3);
+        assert_eq!(cycle, Cycle { mu: 0, lambda: 3 });
+        assert_eq!(cycle.index(7), 1);
+
+        let (cycle, _) = find_cycle("a", |_| "a");
+        assert_eq!(cycle, Cycle { mu: 0, lambda: 1 });
+    }
+}
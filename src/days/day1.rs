@@ -1,9 +1,18 @@
-use aoc2023::{input_filename, read_input};
+use std::hint::black_box;
 
-fn part1(data: &str) -> usize {
+use crate::{Answer, Solution};
+
+/// The non-empty lines of `data`.
+fn parse(data: &str) -> Vec<&[u8]> {
     data.as_bytes()
         .split(|c| *c == b'\n')
         .filter(|s| !s.is_empty())
+        .collect()
+}
+
+fn part1(data: &str) -> usize {
+    parse(data)
+        .into_iter()
         .map(|c| {
             let numbers = c
                 .iter()
@@ -24,9 +33,8 @@ const NUMBERS: [&[u8]; 9] = [
 ];
 
 fn part2(data: &str) -> usize {
-    data.as_bytes()
-        .split(|c| *c == b'\n')
-        .filter(|s| !s.is_empty())
+    parse(data)
+        .into_iter()
         .map(|c| {
             let mut numbers = c
                 .iter()
@@ -56,7 +64,7 @@ fn part2(data: &str) -> usize {
                 }
             }
 
-            numbers.sort_by(|a, b| a.0.cmp(&b.0));
+            numbers.sort_by_key(|a| a.0);
 
             let first = numbers.first().unwrap().1;
             let last = numbers.last().unwrap().1;
@@ -65,19 +73,25 @@ fn part2(data: &str) -> usize {
         .fold(0_usize, |acc, n| acc + usize::from(n))
 }
 
-fn main() {
-    let data = read_input(&format!("./input_{}.txt", input_filename(file!())));
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(parse(input));
+    }
 
-    let numbers = part1(&data);
-    println!("Part1: {numbers}");
+    fn part1(&self, input: &str) -> Answer {
+        Ok(part1(input).to_string())
+    }
 
-    let numbers = part2(&data);
-    println!("Part2: {numbers}");
+    fn part2(&self, input: &str) -> Answer {
+        Ok(part2(input).to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2};
+    use super::{part1, part2};
 
     const SAMPLE: &str = "1abc2
 pqr3stu8vwx
@@ -0,0 +1,625 @@
+use std::{fmt, hint::black_box};
+
+use crate::{
+    geom::{Dir4, Point},
+    grid::{Grid, GridError},
+    Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Pipe {
+    Start,
+    Hor,
+    Ver,
+    BendNE,
+    BendNW,
+    BendSE,
+    BendSW,
+    Ground,
+}
+
+impl Pipe {
+    const PIPES: [Pipe; 6] = [
+        Pipe::Ver,
+        Pipe::Hor,
+        Pipe::BendNE,
+        Pipe::BendNW,
+        Pipe::BendSE,
+        Pipe::BendSW,
+    ];
+
+    /// The pipe connecting sides `a` and `b`, in either order.
+    fn from_openings(a: Dir4, b: Dir4) -> Option<Pipe> {
+        Self::PIPES.into_iter().find(|p| {
+            let o = p.openings();
+            o == Some((a, b)) || o == Some((b, a))
+        })
+    }
+
+    /// The two sides this pipe connects.
+    fn openings(self) -> Option<(Dir4, Dir4)> {
+        match self {
+            Pipe::Ver => Some((Dir4::Up, Dir4::Down)),
+            Pipe::Hor => Some((Dir4::Left, Dir4::Right)),
+            Pipe::BendNE => Some((Dir4::Up, Dir4::Right)),
+            Pipe::BendNW => Some((Dir4::Up, Dir4::Left)),
+            Pipe::BendSE => Some((Dir4::Down, Dir4::Right)),
+            Pipe::BendSW => Some((Dir4::Down, Dir4::Left)),
+            Pipe::Ground | Pipe::Start => None,
+        }
+    }
+
+    /// Entering through the `from` side, the side the pipe leads out of.
+    pub fn exit(self, from: Dir4) -> Option<Dir4> {
+        let (a, b) = self.openings()?;
+        if from == a {
+            Some(b)
+        } else if from == b {
+            Some(a)
+        } else {
+            None
+        }
+    }
+}
+
+impl Pipe {
+    /// Box-drawing version of the pipe, for rendering the loop.
+    fn box_char(self) -> char {
+        match self {
+            Pipe::Ver => '│',
+            Pipe::Hor => '─',
+            Pipe::BendNE => '└',
+            Pipe::BendNW => '┘',
+            Pipe::BendSE => '┌',
+            Pipe::BendSW => '┐',
+            Pipe::Ground => '.',
+            Pipe::Start => 'S',
+        }
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = char;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            '.' => Ok(Self::Ground),
+            '|' => Ok(Self::Ver),
+            '-' => Ok(Self::Hor),
+            'L' => Ok(Self::BendNE),
+            'J' => Ok(Self::BendNW),
+            '7' => Ok(Self::BendSW),
+            'F' => Ok(Self::BendSE),
+            'S' => Ok(Self::Start),
+            e => Err(e),
+        }
+    }
+}
+
+impl From<Pipe> for char {
+    fn from(pipe: Pipe) -> Self {
+        match pipe {
+            Pipe::Ground => '.',
+            Pipe::Ver => '|',
+            Pipe::Hor => '-',
+            Pipe::BendNE => 'L',
+            Pipe::BendNW => 'J',
+            Pipe::BendSW => '7',
+            Pipe::BendSE => 'F',
+            Pipe::Start => 'S',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MapError {
+    /// Ragged rows or an unknown character, with its coordinates.
+    Grid(GridError),
+    /// No `S` tile.
+    MissingStart,
+    /// More than one `S`, the first two found.
+    MultipleStarts { first: Point, second: Point },
+    /// `S` at `at` connects to `found` neighbours instead of two.
+    StartConnections { at: Point, found: usize },
+    /// The path from `S` stops at `at` without coming back.
+    Open { at: Point },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapError::Grid(e) => write!(f, "{e}"),
+            MapError::MissingStart => write!(f, "No start tile"),
+            MapError::MultipleStarts { first, second } => write!(
+                f,
+                "Start tiles at ({}, {}) and ({}, {})",
+                first.x, first.y, second.x, second.y
+            ),
+            MapError::StartConnections { at, found } => write!(
+                f,
+                "Start at ({}, {}) connects to {found} pipes, expected 2",
+                at.x, at.y
+            ),
+            MapError::Open { at } => write!(f, "Loop is open at ({}, {})", at.x, at.y),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+impl From<GridError> for MapError {
+    fn from(e: GridError) -> Self {
+        MapError::Grid(e)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Game {
+    grid: Grid<Pipe>,
+    start: Point,
+    pos: Point,
+    dir: Dir4,
+    /// Every tile visited so far, starting with `S`.
+    path: Vec<Point>,
+}
+
+impl Game {
+    pub fn get(&self, pos: Point) -> Pipe {
+        self.grid[pos]
+    }
+
+    pub fn next(&mut self) -> bool {
+        if let Some(p) = self.pos.step(self.dir, self.grid.bounds()) {
+            let pipe = self.get(p);
+            if let Some(l) = pipe.exit(!self.dir) {
+                self.dir = l;
+                self.pos = p;
+                self.path.push(p);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// The two sides of `S` whose neighbours connect back to it.
+    fn start_openings(&self) -> Result<(Dir4, Dir4), MapError> {
+        let dirs = Dir4::ALL
+            .into_iter()
+            .filter(|dir| {
+                self.start
+                    .step(*dir, self.grid.bounds())
+                    .and_then(|p| self.get(p).exit(!*dir))
+                    .is_some()
+            })
+            .collect::<Vec<Dir4>>();
+
+        match dirs[..] {
+            [a, b] => Ok((a, b)),
+            _ => Err(MapError::StartConnections {
+                at: self.start,
+                found: dirs.len(),
+            }),
+        }
+    }
+
+    /// The pipe hidden under `S`.
+    fn start_pipe(&self) -> Result<Pipe, MapError> {
+        let (a, b) = self.start_openings()?;
+        Ok(Pipe::from_openings(a, b).expect("Every pair of sides has a pipe"))
+    }
+
+    /// Take the first step out of `S`.
+    pub fn find_start(&mut self) -> Result<(), MapError> {
+        let (dir, _) = self.start_openings()?;
+        self.pos = self.start;
+        self.path = vec![self.start];
+        self.dir = dir;
+        self.next();
+        Ok(())
+    }
+
+    /// Follow the pipes from `S` all the way round, the tiles of the loop in
+    /// order, starting with `S`.
+    pub fn find_loop(&mut self) -> Result<Vec<Point>, MapError> {
+        self.find_start()?;
+
+        while self.next() {}
+
+        if self.pos.step(self.dir, self.grid.bounds()) != Some(self.start) {
+            return Err(MapError::Open { at: self.pos });
+        }
+        Ok(std::mem::take(&mut self.path))
+    }
+
+    /// Draw `path` with box-drawing characters, the tiles inside the loop as
+    /// `I` and all others as `O`.
+    pub fn render(&self, path: &[Point]) -> String {
+        let mut tiles = Grid::new(self.grid.width(), self.grid.height(), None);
+        for p in path {
+            tiles[*p] = Some(self.get(*p));
+        }
+        if path.contains(&self.start) {
+            tiles[self.start] = self.start_pipe().ok();
+        }
+
+        let mut out = String::new();
+        for row in tiles.rows() {
+            // Crossing a pipe that goes up flips between outside and inside.
+            let mut inside = false;
+            for tile in row {
+                match tile {
+                    Some(pipe) => {
+                        if matches!(pipe, Pipe::Ver | Pipe::BendNE | Pipe::BendNW) {
+                            inside = !inside;
+                        }
+                        out.push(pipe.box_char());
+                    }
+                    None if inside => out.push('I'),
+                    None => out.push('O'),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Tiles enclosed by the loop `path`.
+///
+/// The shoelace formula gives the area of the loop, Pick's theorem turns
+/// that into the number of points strictly inside: `I = A - B / 2 + 1`.
+fn enclosed(path: &[Point]) -> usize {
+    let twice_area = path
+        .iter()
+        .zip(path.iter().cycle().skip(1))
+        .map(|(a, b)| a.x as isize * b.y as isize - b.x as isize * a.y as isize)
+        .sum::<isize>()
+        .unsigned_abs();
+
+    (twice_area + 2 - path.len()) / 2
+}
+
+fn process(input: &str) -> Result<Game, MapError> {
+    let grid: Grid<Pipe> = input.parse()?;
+
+    let starts = grid
+        .iter()
+        .filter(|(_, pipe)| **pipe == Pipe::Start)
+        .map(|((x, y), _)| Point::new(x, y))
+        .collect::<Vec<Point>>();
+    let start = match starts[..] {
+        [] => return Err(MapError::MissingStart),
+        [start] => start,
+        [first, second, ..] => return Err(MapError::MultipleStarts { first, second }),
+    };
+
+    Ok(Game {
+        grid,
+        start,
+        pos: start,
+        dir: Dir4::Down,
+        path: Vec::new(),
+    })
+}
+
+/// Loop length, farthest tile and enclosed tiles, then the drawn map.
+fn report(input: &str) -> Result<String, MapError> {
+    let mut g = process(input)?;
+    let path = g.find_loop()?;
+
+    Ok(format!(
+        "Loop of {} tiles, farthest {} steps, {} enclosed\n{}",
+        path.len(),
+        path.len() / 2,
+        enclosed(&path),
+        g.render(&path)
+    ))
+}
+
+fn part1(input: &str) -> Answer {
+    let mut g = process(input)?;
+    let path = g.find_loop()?;
+
+    Ok((path.len() / 2).to_string())
+}
+
+fn part2(input: &str) -> Answer {
+    let mut g = process(input)?;
+    let path = g.find_loop()?;
+
+    Ok(enclosed(&path).to_string())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+
+    fn report(&self, input: &str, _arg: Option<&str>) -> Option<Result<String, String>> {
+        Some(report(input).map_err(|e| e.to_string()))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, process, report, Game, MapError, Pipe};
+    use crate::{
+        geom::{Dir4, Point},
+        grid::{Grid, GridError},
+    };
+    const SAMPLE: &str = r#".....
+.S-7.
+.|.|.
+.L-J.
+.....
+    
+"#;
+
+    const SAMPLE_2: &str = r#"7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+"#;
+
+    #[test]
+    fn process_data() {
+        assert_eq!(
+            "7-F7-\r\n".parse::<Grid<Pipe>>(),
+            Grid::from_rows(vec![vec![
+                Pipe::BendSW,
+                Pipe::Hor,
+                Pipe::BendSE,
+                Pipe::BendSW,
+                Pipe::Hor
+            ]])
+        );
+
+        let g = process(SAMPLE_2);
+
+        assert_eq!(
+            g,
+            Ok(Game {
+                grid: Grid::from_rows(vec![
+                    vec![
+                        Pipe::BendSW,
+                        Pipe::Hor,
+                        Pipe::BendSE,
+                        Pipe::BendSW,
+                        Pipe::Hor
+                    ],
+                    vec![
+                        Pipe::Ground,
+                        Pipe::BendSE,
+                        Pipe::BendNW,
+                        Pipe::Ver,
+                        Pipe::BendSW
+                    ],
+                    vec![
+                        Pipe::Start,
+                        Pipe::BendNW,
+                        Pipe::BendNE,
+                        Pipe::BendNE,
+                        Pipe::BendSW
+                    ],
+                    vec![Pipe::Ver, Pipe::BendSE, Pipe::Hor, Pipe::Hor, Pipe::BendNW],
+                    vec![
+                        Pipe::BendNE,
+                        Pipe::BendNW,
+                        Pipe::Ground,
+                        Pipe::BendNE,
+                        Pipe::BendNW
+                    ]
+                ])
+                .unwrap(),
+                start: Point::new(0, 2),
+                pos: Point::new(0, 2),
+                dir: Dir4::Down,
+                path: Vec::new(),
+            })
+        );
+
+        let g = process(SAMPLE_2);
+
+        let mut g = g.unwrap();
+
+        assert_eq!(g.find_start(), Ok(()));
+        assert_eq!(g.pos, Point::new(0, 3));
+        assert_eq!(g.get(g.pos), Pipe::Ver);
+        assert_eq!(g.dir, Dir4::Down);
+
+        assert!(g.next());
+        assert_eq!(g.dir, Dir4::Right);
+        assert_eq!(g.pos, Point::new(0, 4));
+
+        assert!(g.next());
+        assert_eq!(g.dir, Dir4::Up);
+        assert_eq!(g.pos, Point::new(1, 4));
+        assert_eq!(
+            g.path,
+            vec![
+                Point::new(0, 2),
+                Point::new(0, 3),
+                Point::new(0, 4),
+                Point::new(1, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn start_pipe() {
+        let g = process(SAMPLE).unwrap();
+        assert_eq!(g.start_pipe(), Ok(Pipe::BendSE));
+
+        let g = process(".|.\n-S|\n.-.\n").unwrap();
+        assert_eq!(g.start_pipe(), Ok(Pipe::BendNW));
+    }
+
+    #[test]
+    fn find_loop() {
+        let mut g = process(SAMPLE).unwrap();
+        let path = g.find_loop().unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], Point::new(1, 1));
+        assert_eq!(path[1], Point::new(1, 2));
+        assert_eq!(path[7], Point::new(2, 1));
+
+        let mut g = process(".....\n.S-7.\n.|.|.\n.L-..\n").unwrap();
+        assert_eq!(
+            g.find_loop(),
+            Err(MapError::Open {
+                at: Point::new(2, 3)
+            })
+        );
+
+        // Leaves the grid at the bottom edge.
+        let mut g = process("S-\n|.\n").unwrap();
+        assert_eq!(
+            g.find_loop(),
+            Err(MapError::Open {
+                at: Point::new(0, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_maps() {
+        assert_eq!(
+            process("S-7\n|x|\n").err(),
+            Some(MapError::Grid(GridError::InvalidCell {
+                x: 1,
+                y: 1,
+                c: 'x'
+            }))
+        );
+        assert_eq!(
+            process("\nS-7\n\n|x|\n").err(),
+            Some(MapError::Grid(GridError::InvalidCell {
+                x: 1,
+                y: 3,
+                c: 'x'
+            }))
+        );
+        assert_eq!(process("F7\nLJ\n").err(), Some(MapError::MissingStart));
+        assert_eq!(
+            process("S7\nLS\n").err(),
+            Some(MapError::MultipleStarts {
+                first: Point::new(0, 0),
+                second: Point::new(1, 1)
+            })
+        );
+
+        let mut g = process(".|.\n-S-\n.|.\n").unwrap();
+        assert_eq!(
+            g.find_loop(),
+            Err(MapError::StartConnections {
+                at: Point::new(1, 1),
+                found: 4
+            })
+        );
+
+        let mut g = process("S.\n..\n").unwrap();
+        assert_eq!(
+            g.find_start(),
+            Err(MapError::StartConnections {
+                at: Point::new(0, 0),
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut g = process(SAMPLE).unwrap();
+        let path = g.find_loop().unwrap();
+        assert_eq!(g.render(&path), "OOOOO\nO┌─┐O\nO│I│O\nO└─┘O\nOOOOO\n");
+
+        assert_eq!(
+            report(SAMPLE),
+            Ok("Loop of 8 tiles, farthest 4 steps, 1 enclosed\n".to_string() + &g.render(&path))
+        );
+        assert_eq!(
+            report("S.\n").err(),
+            Some(MapError::StartConnections {
+                at: Point::new(0, 0),
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "4");
+    }
+
+    #[test]
+    fn example_1_s2() {
+        assert_eq!(part1(SAMPLE_2).unwrap(), "8");
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "1");
+        assert_eq!(part2(SAMPLE_2).unwrap(), "1");
+    }
+
+    #[test]
+    fn example_2_squeeze() {
+        const OPEN: &str = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+        const SQUEEZE: &str = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+        const LARGER: &str = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+        const JUNK: &str = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+        assert_eq!(part2(OPEN).unwrap(), "4");
+        assert_eq!(part2(SQUEEZE).unwrap(), "4");
+        assert_eq!(part2(LARGER).unwrap(), "8");
+        assert_eq!(part2(JUNK).unwrap(), "10");
+    }
+}
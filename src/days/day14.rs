@@ -0,0 +1,495 @@
+use std::{fmt, hint::black_box};
+
+use crate::{
+    cycle::{find_cycle, Cycle},
+    grid::{Grid, GridError},
+    Answer, Solution,
+};
+
+/// The most rows and columns a board can have, one bit per tile in a `u128`.
+const SIZE: usize = 128;
+
+/// A small counter per column, bit `i` of every count is in plane `i`.
+///
+/// Eight planes count up to 255, enough for a column of [`SIZE`] tiles.
+#[derive(Debug, Default, Clone, Copy)]
+struct Counts([u128; 8]);
+
+impl Counts {
+    /// Add one in the columns of `mask`.
+    fn add(&mut self, mask: u128) {
+        let mut carry = mask;
+        for plane in &mut self.0 {
+            let next = *plane & carry;
+            *plane ^= carry;
+            carry = next;
+        }
+    }
+
+    /// Subtract one in the columns of `mask`, none of which may be zero.
+    fn sub(&mut self, mask: u128) {
+        let mut borrow = mask;
+        for plane in &mut self.0 {
+            let next = !*plane & borrow;
+            *plane ^= borrow;
+            borrow = next;
+        }
+    }
+
+    /// The columns with a count above zero.
+    fn nonzero(&self) -> u128 {
+        self.0.iter().fold(0, |acc, plane| acc | plane)
+    }
+
+    fn clear(&mut self, mask: u128) {
+        for plane in &mut self.0 {
+            *plane &= !mask;
+        }
+    }
+
+    /// Take the counts of the columns in `mask` from `other`.
+    fn load(&mut self, mask: u128, other: &Self) {
+        for (plane, from) in self.0.iter_mut().zip(other.0) {
+            *plane = *plane & !mask | from & mask;
+        }
+    }
+}
+
+/// Tilt the round rocks towards `round[0]`, all columns at once.
+///
+/// The cube rocks cut every column into segments. A pass from the far end
+/// counts the round rocks of each segment, a pass from the near end stacks
+/// that many rocks from the start of the segment. Both take `O(rows)` word
+/// operations.
+fn tilt(round: &mut [u128], cube: &[u128]) {
+    // The columns in which a segment starts at row `y`.
+    let starts = |y: usize| !cube[y] & if y == 0 { u128::MAX } else { cube[y - 1] };
+
+    let mut counts = Counts::default();
+    let mut segments = vec![Counts::default(); round.len()];
+    for y in (0..round.len()).rev() {
+        counts.clear(cube[y]);
+        counts.add(round[y]);
+        segments[y] = counts;
+    }
+
+    let mut counts = Counts::default();
+    for y in 0..round.len() {
+        counts.load(starts(y), &segments[y]);
+        round[y] = counts.nonzero() & !cube[y];
+        counts.sub(round[y]);
+    }
+}
+
+/// Swap rows and columns: bit `x` of line `y` becomes bit `y` of line `x`.
+///
+/// Swaps the off-diagonal halves of ever smaller blocks, 7 rounds for 128
+/// lines.
+fn transpose(lines: &[u128]) -> [u128; SIZE] {
+    let mut a = [0; SIZE];
+    a[..lines.len()].copy_from_slice(lines);
+
+    let mut j = SIZE / 2;
+    let mut m = u128::from(u64::MAX);
+    while j != 0 {
+        let mut k = 0;
+        while k < SIZE {
+            let t = ((a[k] >> j) ^ a[k + j]) & m;
+            a[k] ^= t << j;
+            a[k + j] ^= t;
+            k = (k + j + 1) & !j;
+        }
+        j /= 2;
+        m ^= m << j;
+    }
+
+    a
+}
+
+/// Bit `x` of row `y` is the tile at `(x, y)`, boards are at most 128 by 128.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+struct Game {
+    width: usize,
+    round: Vec<u128>,
+    cube: Vec<u128>,
+    /// `cube` transposed, bit `y` of column `x`.
+    cube_t: Vec<u128>,
+}
+
+impl Game {
+    pub fn move_north(&mut self) {
+        tilt(&mut self.round, &self.cube);
+    }
+
+    pub fn move_south(&mut self) {
+        self.round.reverse();
+        self.cube.reverse();
+        tilt(&mut self.round, &self.cube);
+        self.round.reverse();
+        self.cube.reverse();
+    }
+
+    /// Tilt the transposed board, towards column 0 for west.
+    fn move_sideways(&mut self, west: bool) {
+        let mut columns = transpose(&self.round);
+        let columns = &mut columns[..self.width];
+        if west {
+            tilt(columns, &self.cube_t);
+        } else {
+            columns.reverse();
+            self.cube_t.reverse();
+            tilt(columns, &self.cube_t);
+            columns.reverse();
+            self.cube_t.reverse();
+        }
+
+        let rows = self.round.len();
+        self.round.copy_from_slice(&transpose(columns)[..rows]);
+    }
+
+    pub fn move_east(&mut self) {
+        self.move_sideways(false);
+    }
+
+    pub fn move_west(&mut self) {
+        self.move_sideways(true);
+    }
+
+    /// One spin cycle: tilt north, west, south and east.
+    pub fn spin(&mut self) {
+        self.move_north();
+        self.move_west();
+        self.move_south();
+        self.move_east();
+    }
+
+    pub fn score(&self) -> usize {
+        let rows = self.round.len();
+
+        self.round
+            .iter()
+            .enumerate()
+            .map(|(idx, row)| row.count_ones() as usize * (rows - idx))
+            .sum::<usize>()
+    }
+}
+
+impl TryFrom<Grid<I>> for Game {
+    type Error = BoardError;
+
+    fn try_from(grid: Grid<I>) -> Result<Self, Self::Error> {
+        if grid.width() > SIZE {
+            return Err(BoardError::TooWide(grid.width()));
+        }
+        if grid.height() > SIZE {
+            return Err(BoardError::TooTall(grid.height()));
+        }
+
+        let line = |row: &[I], item: I| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, i)| **i == item)
+                .fold(0, |mask, (x, _)| mask | 1 << x)
+        };
+
+        let cube = grid
+            .rows()
+            .map(|row| line(row, I::C))
+            .collect::<Vec<u128>>();
+        Ok(Self {
+            width: grid.width(),
+            round: grid.rows().map(|row| line(row, I::R)).collect(),
+            cube_t: transpose(&cube)[..grid.width()].to_vec(),
+            cube,
+        })
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (round, cube) in self.round.iter().zip(&self.cube) {
+            for x in 0..self.width {
+                let item = if round >> x & 1 == 1 {
+                    I::R
+                } else if cube >> x & 1 == 1 {
+                    I::C
+                } else {
+                    I::E
+                };
+                write!(f, "{}", char::from(item))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BoardError {
+    Grid(GridError),
+    /// More columns than fit in a `u128`.
+    TooWide(usize),
+    /// More rows than fit in a `u128`, for tilting east and west.
+    TooTall(usize),
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::Grid(e) => write!(f, "{e}"),
+            BoardError::TooWide(width) => write!(f, "Board is {width} wide, at most {SIZE} fit"),
+            BoardError::TooTall(height) => write!(f, "Board is {height} tall, at most {SIZE} fit"),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+impl From<GridError> for BoardError {
+    fn from(e: GridError) -> Self {
+        BoardError::Grid(e)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum I {
+    /// empty spaces (.)
+    E,
+    /// single rounded rock (O)
+    R,
+    /// Cube-shaped rocks (#) don't contribute to load.)
+    C,
+}
+
+impl TryFrom<char> for I {
+    type Error = char;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
+        match item {
+            '#' => Ok(Self::C),
+            'O' => Ok(Self::R),
+            '.' => Ok(Self::E),
+            e => Err(e),
+        }
+    }
+}
+
+impl From<I> for char {
+    fn from(item: I) -> Self {
+        match item {
+            I::E => '.',
+            I::R => 'O',
+            I::C => '#',
+        }
+    }
+}
+
+fn process(input: &str) -> Result<Game, BoardError> {
+    let grid: Grid<I> = input.parse()?;
+    Game::try_from(grid)
+}
+
+fn part1(input: &str) -> Answer {
+    let mut g = process(input)?;
+
+    g.move_north();
+
+    Ok(g.score().to_string())
+}
+
+/// Every board from the start until the spins go round in circles.
+struct History {
+    cycle: Cycle,
+    /// `states[n]` is the board after `n` spins.
+    states: Vec<Game>,
+}
+
+impl History {
+    fn new(game: Game) -> Self {
+        let (cycle, states) = find_cycle(game, |g| {
+            let mut g = g.clone();
+            g.spin();
+            g
+        });
+        Self { cycle, states }
+    }
+
+    /// The board after `n` spins, for any `n`.
+    fn at(&self, n: usize) -> &Game {
+        &self.states[self.cycle.index(n)]
+    }
+
+    /// The north load after every spin up to the first repeated board.
+    fn csv(&self) -> String {
+        let mut out = "spin,load\n".to_string();
+        for (n, g) in self.states.iter().enumerate() {
+            out.push_str(&format!("{n},{}\n", g.score()));
+        }
+        out
+    }
+
+    /// Without `arg` only the cycle, `csv` for the loads, or a spin number
+    /// to show the board after that many spins.
+    fn report(&self, arg: Option<&str>) -> Result<String, String> {
+        let summary = format!(
+            "Cycle starts at spin {}, period {}\n",
+            self.cycle.mu, self.cycle.lambda
+        );
+
+        match arg {
+            None => Ok(summary),
+            Some("csv") => Ok(self.csv()),
+            Some(n) => {
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("Expected csv or a spin number, got {n}"))?;
+                let g = self.at(n);
+                Ok(format!("{summary}Spin {n}, load {}:\n{g}", g.score()))
+            }
+        }
+    }
+}
+
+fn part2(input: &str) -> Answer {
+    let g = process(input)?;
+
+    Ok(History::new(g).at(1_000_000_000).score().to_string())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+
+    fn report(&self, input: &str, arg: Option<&str>) -> Option<Result<String, String>> {
+        let g = process(input).map_err(|e| e.to_string());
+        Some(g.and_then(|g| History::new(g).report(arg)))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, process, tilt, transpose, BoardError, History, I};
+    use crate::grid::Grid;
+    const SAMPLE: &str = r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+
+"#;
+    #[test]
+    fn process_data() {
+        assert_eq!(
+            "O.#.\n".parse::<Grid<I>>(),
+            Grid::from_rows(vec![vec![I::R, I::E, I::C, I::E]])
+        );
+
+        assert!(process(SAMPLE).is_ok());
+    }
+
+    #[test]
+    fn tilts() {
+        // Column 0 is O.O#.O.., column 1 is .OO.O#OO
+        let mut round = [0b01, 0b10, 0b11, 0b00, 0b11, 0b00, 0b10, 0b10];
+        let cube = [0b00, 0b00, 0b00, 0b01, 0b00, 0b10, 0b00, 0b00];
+        tilt(&mut round, &cube);
+        assert_eq!(round, [0b11, 0b11, 0b10, 0b00, 0b01, 0b00, 0b10, 0b10]);
+
+        let lines = (0..128)
+            .map(|y| 1 << y | 1 << (y * 7 % 128))
+            .collect::<Vec<u128>>();
+        let t = transpose(&lines);
+        assert_eq!(transpose(&t).to_vec(), lines);
+        assert_eq!(transpose(&[0b110])[..3], [0, 1, 1]);
+
+        let mut g = process("..\nO#\n.O\nO.\n").unwrap();
+        g.move_north();
+        assert_eq!(g.to_string(), "O.\nO#\n.O\n..\n");
+        g.move_south();
+        assert_eq!(g.to_string(), "..\n.#\nO.\nOO\n");
+        g.move_east();
+        assert_eq!(g.to_string(), "..\n.#\n.O\nOO\n");
+
+        let mut full = process(&format!("O{}\n", ".".repeat(127))).unwrap();
+        full.move_east();
+        assert_eq!(full.to_string(), format!("{}O\n", ".".repeat(127)));
+        full.move_west();
+        full.move_north();
+        assert_eq!(full.to_string(), format!("O{}\n", ".".repeat(127)));
+
+        let mut tall = process(&"O\n".repeat(127).replacen("O\nO\nO\n", "O\n#\n.\n", 1)).unwrap();
+        tall.move_south();
+        tall.move_east();
+        // O # . then 124 rocks at the bottom.
+        assert_eq!(tall.score(), 127 + 124 * 125 / 2);
+
+        let wide = ".".repeat(129);
+        assert_eq!(process(&wide), Err(BoardError::TooWide(129)));
+        assert_eq!(process(&".\n".repeat(129)), Err(BoardError::TooTall(129)));
+    }
+
+    #[test]
+    fn spin() {
+        let mut g = process(SAMPLE).unwrap();
+        g.spin();
+        assert_eq!(
+            g.to_string(),
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+"
+        );
+    }
+
+    #[test]
+    fn history() {
+        let h = History::new(process(SAMPLE).unwrap());
+        assert_eq!((h.cycle.mu, h.cycle.lambda), (3, 7));
+        assert_eq!(h.at(1_000_000_000).score(), 64);
+
+        let csv = h.csv();
+        assert_eq!(csv.lines().count(), 11);
+        assert!(csv.starts_with("spin,load\n0,104\n1,87\n"));
+
+        assert_eq!(
+            h.report(None),
+            Ok("Cycle starts at spin 3, period 7\n".to_string())
+        );
+        let report = h.report(Some("1000000000")).unwrap();
+        assert!(report.contains("Spin 1000000000, load 64:\n"));
+        assert!(report.ends_with(&h.at(1_000_000_000).to_string()));
+        assert!(h.report(Some("soon")).is_err());
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "136");
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "64");
+    }
+}
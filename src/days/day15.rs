@@ -0,0 +1,255 @@
+use std::{fmt, hint::black_box};
+
+use crate::{lens, lens::LensMap, Answer, Solution};
+use winnow::{
+    self,
+    ascii::line_ending,
+    combinator::{eof, opt, separated},
+    token::take_till,
+    PResult, Parser,
+};
+
+fn hashes(input: Vec<&str>) -> Vec<u8> {
+    input.into_iter().map(hash).collect()
+}
+
+fn hash(input: &str) -> u8 {
+    lens::hash(input.as_bytes())
+}
+
+fn parse_line<'a>(input: &mut &'a str) -> PResult<Vec<&'a str>> {
+    let row = separated(
+        1..,
+        take_till(1.., |c| c == ',' || c == '\n' || c == '\r'),
+        ',',
+    )
+    .parse_next(input)?;
+    line_ending.parse_next(input)?;
+
+    Ok(row)
+}
+
+fn process(input: &str) -> PResult<Vec<&str>> {
+    let mut input = input;
+    let input = &mut input;
+    let hash = parse_line.parse_next(input)?;
+    opt(line_ending).parse_next(input)?;
+    eof.parse_next(input)?;
+    Ok(hash)
+}
+
+fn part1(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
+
+    let sum = hashes(g)
+        .iter()
+        .fold(0_usize, |acc, x| acc + usize::from(*x));
+    Ok(sum.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+enum Act<'a> {
+    Del(&'a str),
+    Add(&'a str, u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum StepError {
+    /// Neither `label-` nor `label=n`.
+    MissingOperation(String),
+    /// The label is empty or has a `=` or `-` in it.
+    InvalidLabel(String),
+    /// The focal length is not a number from 1 to 9.
+    InvalidFocalLength(String),
+}
+
+impl fmt::Display for StepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StepError::MissingOperation(step) => write!(f, "No operation in {step:?}"),
+            StepError::InvalidLabel(step) => write!(f, "Invalid label in {step:?}"),
+            StepError::InvalidFocalLength(step) => {
+                write!(f, "Focal length must be 1 to 9 in {step:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StepError {}
+
+/// `label-` or `label=n`, the label may be anything but `=` and `-`.
+fn parse_box(step: &str) -> Result<Act<'_>, StepError> {
+    let (label, act) = if let Some(label) = step.strip_suffix('-') {
+        (label, Act::Del(label))
+    } else if let Some((label, lens)) = step.split_once('=') {
+        let lens = lens
+            .parse::<u32>()
+            .ok()
+            .filter(|lens| (1..=9).contains(lens))
+            .ok_or_else(|| StepError::InvalidFocalLength(step.to_string()))?;
+        (label, Act::Add(label, lens as u8))
+    } else {
+        return Err(StepError::MissingOperation(step.to_string()));
+    };
+
+    if label.is_empty() || label.contains(['=', '-']) {
+        return Err(StepError::InvalidLabel(step.to_string()));
+    }
+    Ok(act)
+}
+
+fn apply<'a>(boxes: &mut LensMap<&'a str, u8>, act: Act<'a>) {
+    match act {
+        Act::Del(label) => {
+            boxes.remove(label);
+        }
+        Act::Add(label, lens) => {
+            boxes.insert(label, lens);
+        }
+    }
+}
+
+/// Run every step on a fresh map.
+fn arrange<'a>(steps: &[&'a str]) -> Result<LensMap<&'a str, u8>, StepError> {
+    let mut boxes = LensMap::new();
+    for step in steps {
+        apply(&mut boxes, parse_box(step)?);
+    }
+    Ok(boxes)
+}
+
+/// The boxes after every step, worded like the puzzle walkthrough.
+fn trace(steps: &[&str]) -> Result<String, StepError> {
+    let mut boxes = LensMap::new();
+    let mut out = String::new();
+
+    for step in steps {
+        apply(&mut boxes, parse_box(step)?);
+        out.push_str(&format!("After \"{step}\":\n{boxes}\n"));
+    }
+
+    Ok(out)
+}
+
+fn part2(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
+
+    Ok(arrange(&g)?.focusing_power().to_string())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+
+    fn report(&self, input: &str, _arg: Option<&str>) -> Option<Result<String, String>> {
+        let steps = process(input).map_err(|e| e.to_string());
+        Some(steps.and_then(|steps| trace(&steps).map_err(|e| e.to_string())))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use winnow::Parser;
+
+    use super::{arrange, hashes, parse_box, parse_line, part1, part2, trace, Act, StepError};
+    const SAMPLE: &str = r#"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7
+"#;
+    #[test]
+    fn process_data() {
+        let data = parse_line.parse_peek(SAMPLE);
+        assert_eq!(
+            data,
+            Ok((
+                "",
+                vec![
+                    "rn=1", "cm-", "qp=3", "cm=2", "qp-", "pc=4", "ot=9", "ab=5", "pc-", "pc=6",
+                    "ot=7"
+                ]
+            ))
+        );
+
+        assert_eq!(
+            hashes(data.unwrap().1),
+            vec![30_u8, 253, 97, 47, 14, 180, 9, 197, 48, 214, 231]
+        );
+
+        assert_eq!(parse_box("rn=1"), Ok(Act::Add("rn", 1)));
+        assert_eq!(parse_box("cm-"), Ok(Act::Del("cm")));
+        assert_eq!(parse_box("a.b:c=9"), Ok(Act::Add("a.b:c", 9)));
+        assert_eq!(parse_box("07-"), Ok(Act::Del("07")));
+        assert_eq!(
+            parse_box("rn=10"),
+            Err(StepError::InvalidFocalLength("rn=10".to_string()))
+        );
+        assert_eq!(
+            parse_box("rn=0"),
+            Err(StepError::InvalidFocalLength("rn=0".to_string()))
+        );
+        assert_eq!(
+            parse_box("rn"),
+            Err(StepError::MissingOperation("rn".to_string()))
+        );
+        assert_eq!(
+            parse_box("r-n-"),
+            Err(StepError::InvalidLabel("r-n-".to_string()))
+        );
+        assert_eq!(
+            parse_box("=1"),
+            Err(StepError::InvalidLabel("=1".to_string()))
+        );
+    }
+
+    #[test]
+    fn walkthrough() {
+        let steps = [
+            "rn=1", "cm-", "qp=3", "cm=2", "qp-", "pc=4", "ot=9", "ab=5", "pc-", "pc=6", "ot=7",
+        ];
+        let states = [
+            r#"{0: [("rn", 1)]}"#,
+            r#"{0: [("rn", 1)]}"#,
+            r#"{0: [("rn", 1)], 1: [("qp", 3)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 1: [("qp", 3)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("pc", 4)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("pc", 4), ("ot", 9)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("pc", 4), ("ot", 9), ("ab", 5)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("ot", 9), ("ab", 5)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("ot", 9), ("ab", 5), ("pc", 6)]}"#,
+            r#"{0: [("rn", 1), ("cm", 2)], 3: [("ot", 7), ("ab", 5), ("pc", 6)]}"#,
+        ];
+
+        for n in 0..steps.len() {
+            assert_eq!(format!("{:?}", arrange(&steps[..=n]).unwrap()), states[n]);
+        }
+        assert_eq!(arrange(&steps).unwrap().focusing_power(), 145);
+
+        let trace = trace(&steps).unwrap();
+        assert!(trace.starts_with("After \"rn=1\":\nBox 0: [rn 1]\n\nAfter \"cm-\":\n"));
+        assert!(trace
+            .ends_with("After \"ot=7\":\nBox 0: [rn 1] [cm 2]\nBox 3: [ot 7] [ab 5] [pc 6]\n\n"));
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "1320");
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "145");
+        assert_eq!(
+            part2("a=0\n").unwrap_err().to_string(),
+            StepError::InvalidFocalLength("a=0".to_string()).to_string()
+        );
+    }
+}
@@ -0,0 +1,522 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hint::black_box,
+};
+
+use petgraph::{algo::tarjan_scc, graph::DiGraph};
+use rayon::prelude::*;
+
+use crate::{
+    geom::{Dir4, Point},
+    grid::{Grid, GridError},
+    Answer, Solution,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Tile {
+    /// '.'
+    Empty,
+    /// '-'
+    SplitHor,
+    /// '|'
+    SplitVer,
+    /// '/'
+    Slash,
+    /// '\\'
+    BackSlash,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            '.' => Ok(Self::Empty),
+            '|' => Ok(Self::SplitVer),
+            '-' => Ok(Self::SplitHor),
+            '/' => Ok(Self::Slash),
+            '\\' => Ok(Self::BackSlash),
+            e => Err(e),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::SplitHor => '-',
+            Tile::SplitVer => '|',
+            Tile::Slash => '/',
+            Tile::BackSlash => '\\',
+        }
+    }
+}
+
+impl Tile {
+    /// The directions a beam heading `dir` leaves this tile in.
+    fn exits(self, dir: Dir4) -> (Dir4, Option<Dir4>) {
+        match (self, dir) {
+            (Tile::Slash, Dir4::Up) => (Dir4::Right, None),
+            (Tile::Slash, Dir4::Down) => (Dir4::Left, None),
+            (Tile::Slash, Dir4::Left) => (Dir4::Down, None),
+            (Tile::Slash, Dir4::Right) => (Dir4::Up, None),
+            (Tile::BackSlash, Dir4::Up) => (Dir4::Left, None),
+            (Tile::BackSlash, Dir4::Down) => (Dir4::Right, None),
+            (Tile::BackSlash, Dir4::Left) => (Dir4::Up, None),
+            (Tile::BackSlash, Dir4::Right) => (Dir4::Down, None),
+            (Tile::SplitHor, Dir4::Up | Dir4::Down) => (Dir4::Left, Some(Dir4::Right)),
+            (Tile::SplitVer, Dir4::Left | Dir4::Right) => (Dir4::Up, Some(Dir4::Down)),
+            _ => (dir, None),
+        }
+    }
+}
+
+/// Fixed size set of small integers, one bit each.
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct BitSet(Vec<u64>);
+
+impl BitSet {
+    fn new(len: usize) -> Self {
+        Self(vec![0; len.div_ceil(64)])
+    }
+
+    /// Add `idx`, returns `false` when it was already there.
+    fn insert(&mut self, idx: usize) -> bool {
+        let (word, bit) = (idx / 64, 1 << (idx % 64));
+        let new = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        new
+    }
+
+    fn contains(&self, idx: usize) -> bool {
+        self.0[idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    fn count(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    fn union_with(&mut self, other: &BitSet) {
+        for (a, b) in self.0.iter_mut().zip(&other.0) {
+            *a |= b;
+        }
+    }
+}
+
+/// The tiles a beam passes through.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Energized {
+    width: usize,
+    tiles: BitSet,
+}
+
+impl Energized {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            tiles: BitSet::new(width * height),
+        }
+    }
+
+    fn insert(&mut self, pos: Point) {
+        self.tiles.insert(pos.y * self.width + pos.x);
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.tiles.contains(pos.y * self.width + pos.x)
+    }
+
+    pub fn count(&self) -> usize {
+        self.tiles.count()
+    }
+
+    pub fn union_with(&mut self, other: &Energized) {
+        self.tiles.union_with(&other.tiles);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Game {
+    grid: Grid<Tile>,
+}
+
+impl Game {
+    /// Follow a beam entering `pos` heading `dir` and all beams split off it.
+    ///
+    /// Every `(position, direction)` state is handled once, so loops between
+    /// splitters and mirrors end on their own.
+    pub fn energize(&self, pos: Point, dir: Dir4) -> Energized {
+        let bounds = self.grid.bounds();
+        let mut seen = BitSet::new(bounds.x * bounds.y * 4);
+        let mut energized = Energized::new(bounds.x, bounds.y);
+        let mut queue = VecDeque::from([(pos, dir)]);
+
+        while let Some((pos, dir)) = queue.pop_front() {
+            if !seen.insert((pos.y * bounds.x + pos.x) * 4 + dir as usize) {
+                continue;
+            }
+            energized.insert(pos);
+
+            let (a, b) = self.grid[pos].exits(dir);
+            for dir in [Some(a), b].into_iter().flatten() {
+                if let Some(next) = pos.step(dir, bounds) {
+                    queue.push_back((next, dir));
+                }
+            }
+        }
+
+        energized
+    }
+
+    /// Every way a beam can enter from the edge, pointing into the grid.
+    pub fn edge_starts(&self) -> Vec<(Point, Dir4)> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+
+        (0..height)
+            .flat_map(|y| {
+                [
+                    (Point::new(0, y), Dir4::Right),
+                    (Point::new(width - 1, y), Dir4::Left),
+                ]
+            })
+            .chain((0..width).flat_map(|x| {
+                [
+                    (Point::new(x, 0), Dir4::Down),
+                    (Point::new(x, height - 1), Dir4::Up),
+                ]
+            }))
+            .collect()
+    }
+
+    /// Follow a single beam until it reaches a splitter that splits it.
+    ///
+    /// Returns the tiles passed, the splitter included, and the position of
+    /// that splitter, `None` when the beam leaves the grid instead.
+    fn segment(&self, mut pos: Point, mut dir: Dir4) -> (Energized, Option<Point>) {
+        let bounds = self.grid.bounds();
+        let mut seen = BitSet::new(bounds.x * bounds.y * 4);
+        let mut energized = Energized::new(bounds.x, bounds.y);
+
+        while seen.insert((pos.y * bounds.x + pos.x) * 4 + dir as usize) {
+            energized.insert(pos);
+            match self.grid[pos].exits(dir) {
+                (_, Some(_)) => return (energized, Some(pos)),
+                (next, None) => dir = next,
+            }
+            match pos.step(dir, bounds) {
+                Some(next) => pos = next,
+                None => break,
+            }
+        }
+
+        (energized, None)
+    }
+
+    /// Best edge start, every start simulated on its own in parallel.
+    pub fn max_energized(&self) -> usize {
+        self.edge_starts()
+            .par_iter()
+            .map(|(pos, dir)| self.energize(*pos, *dir).count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Best edge start, sharing what each splitter energizes between starts.
+    pub fn max_energized_memo(&self) -> usize {
+        let memo = SplitterMemo::new(self);
+
+        self.edge_starts()
+            .par_iter()
+            .map(|(pos, dir)| memo.energize(self, *pos, *dir).count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The beam of `energize` spreading out, one frame per step.
+    pub fn frames(&self, pos: Point, dir: Dir4) -> Vec<String> {
+        let bounds = self.grid.bounds();
+        // One bit per direction a beam crossed the tile in.
+        let mut beams = self.grid.map(|_| 0_u8);
+        let mut frames = Vec::new();
+        let mut front = vec![(pos, dir)];
+
+        while !front.is_empty() {
+            let mut next = Vec::new();
+            for (pos, dir) in front {
+                let bit = 1 << dir as u8;
+                if beams[pos] & bit != 0 {
+                    continue;
+                }
+                beams[pos] |= bit;
+
+                let (a, b) = self.grid[pos].exits(dir);
+                for dir in [Some(a), b].into_iter().flatten() {
+                    if let Some(p) = pos.step(dir, bounds) {
+                        next.push((p, dir));
+                    }
+                }
+            }
+
+            if !next.is_empty() || frames.is_empty() {
+                frames.push(self.render(&beams));
+            }
+            front = next;
+        }
+
+        frames
+    }
+
+    /// Empty tiles show the direction of the beam crossing them, or how many
+    /// beams do when there is more than one.
+    fn render(&self, beams: &Grid<u8>) -> String {
+        let mut out = String::new();
+        for (tiles, marks) in self.grid.rows().zip(beams.rows()) {
+            for (tile, mark) in tiles.iter().zip(marks) {
+                out.push(match (tile, mark.count_ones()) {
+                    (Tile::Empty, 1) => match Dir4::ALL[mark.trailing_zeros() as usize] {
+                        Dir4::Up => '^',
+                        Dir4::Down => 'v',
+                        Dir4::Left => '<',
+                        Dir4::Right => '>',
+                    },
+                    (Tile::Empty, n @ 2..) => char::from_digit(n, 10).unwrap(),
+                    (tile, _) => (*tile).into(),
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Everything energized once a beam is split by each splitter.
+///
+/// Splitters are the nodes of a graph, with an edge to every splitter one of
+/// their two outgoing beams runs into. Splitters feeding each other end up in
+/// one strongly connected component and share a single set.
+struct SplitterMemo {
+    nodes: HashMap<Point, usize>,
+    energized: Vec<Energized>,
+}
+
+impl SplitterMemo {
+    fn new(game: &Game) -> Self {
+        let bounds = game.grid.bounds();
+        let mut graph = DiGraph::<Point, ()>::new();
+        let mut nodes = HashMap::new();
+
+        for ((x, y), tile) in game.grid.iter() {
+            if matches!(tile, Tile::SplitHor | Tile::SplitVer) {
+                nodes.insert(Point::new(x, y), graph.add_node(Point::new(x, y)));
+            }
+        }
+
+        // Tiles of the two beams leaving each splitter.
+        let mut own = vec![Energized::new(bounds.x, bounds.y); graph.node_count()];
+        for (pos, node) in nodes.iter() {
+            let dirs = match game.grid[*pos] {
+                Tile::SplitHor => [Dir4::Left, Dir4::Right],
+                _ => [Dir4::Up, Dir4::Down],
+            };
+            own[node.index()].insert(*pos);
+
+            for dir in dirs {
+                let Some(next) = pos.step(dir, bounds) else {
+                    continue;
+                };
+                let (tiles, hit) = game.segment(next, dir);
+                own[node.index()].union_with(&tiles);
+                if let Some(hit) = hit {
+                    graph.add_edge(*node, nodes[&hit], ());
+                }
+            }
+        }
+
+        // Components come successors first, so theirs are always ready.
+        let mut energized = own.clone();
+        for scc in tarjan_scc(&graph) {
+            let mut set = Energized::new(bounds.x, bounds.y);
+            for node in &scc {
+                set.union_with(&own[node.index()]);
+                for next in graph.neighbors(*node) {
+                    set.union_with(&energized[next.index()]);
+                }
+            }
+            for node in &scc {
+                energized[node.index()] = set.clone();
+            }
+        }
+
+        Self {
+            nodes: nodes.into_iter().map(|(p, n)| (p, n.index())).collect(),
+            energized,
+        }
+    }
+
+    fn energize(&self, game: &Game, pos: Point, dir: Dir4) -> Energized {
+        let (mut tiles, hit) = game.segment(pos, dir);
+        if let Some(hit) = hit {
+            tiles.union_with(&self.energized[self.nodes[&hit]]);
+        }
+        tiles
+    }
+}
+
+/// Share the splitter sets between the edge starts of part 2.
+const MEMO: bool = true;
+
+fn process(input: &str) -> Result<Game, GridError> {
+    Ok(Game {
+        grid: input.parse()?,
+    })
+}
+
+fn part1(input: &str) -> Answer {
+    let g = process(input)?;
+
+    let count = g.energize(Point::new(0, 0), Dir4::Right).count();
+    Ok(count.to_string())
+}
+
+fn part2(input: &str) -> Answer {
+    let g = process(input)?;
+
+    let max = if MEMO {
+        g.max_energized_memo()
+    } else {
+        g.max_energized()
+    };
+    Ok(max.to_string())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn frames(&self, input: &str) -> Option<Result<Vec<String>, String>> {
+        let g = process(input).map_err(|e| e.to_string());
+        Some(g.map(|g| g.frames(Point::new(0, 0), Dir4::Right)))
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2, process, Day16, SplitterMemo, Tile};
+    use crate::{
+        geom::{Dir4, Point},
+        grid::Grid,
+        Solution,
+    };
+    const SAMPLE: &str = r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+    
+"#;
+
+    #[test]
+    fn process_data() {
+        assert_eq!(
+            "|.-.\\/\r\n".parse::<Grid<Tile>>(),
+            Grid::from_rows(vec![vec![
+                Tile::SplitVer,
+                Tile::Empty,
+                Tile::SplitHor,
+                Tile::Empty,
+                Tile::BackSlash,
+                Tile::Slash,
+            ]])
+        );
+    }
+
+    #[test]
+    fn energize() {
+        let g = process(SAMPLE).unwrap();
+
+        let e = g.energize(Point::new(0, 0), Dir4::Right);
+        assert_eq!(e.count(), 46);
+        assert!(e.contains(Point::new(1, 0)));
+        assert!(!e.contains(Point::new(7, 0)));
+
+        assert_eq!(g.energize(Point::new(3, 0), Dir4::Down).count(), 51);
+
+        // Two splitters feeding each other.
+        let g = process("|-\n-|\n").unwrap();
+        assert_eq!(g.energize(Point::new(0, 0), Dir4::Right).count(), 4);
+
+        let starts = g.edge_starts();
+        assert_eq!(starts.len(), 8);
+        assert!(starts.contains(&(Point::new(1, 1), Dir4::Up)));
+        assert!(starts.contains(&(Point::new(1, 1), Dir4::Left)));
+    }
+
+    #[test]
+    fn memo() {
+        let g = process(SAMPLE).unwrap();
+        assert_eq!(g.max_energized(), 51);
+        assert_eq!(g.max_energized_memo(), 51);
+
+        let memo = SplitterMemo::new(&g);
+        for (pos, dir) in g.edge_starts() {
+            assert_eq!(memo.energize(&g, pos, dir), g.energize(pos, dir));
+        }
+
+        let g = process("|-\n-|\n").unwrap();
+        assert_eq!(g.max_energized_memo(), 4);
+    }
+
+    #[test]
+    fn frames() {
+        let g = process(SAMPLE).unwrap();
+        let frames = g.frames(Point::new(0, 0), Dir4::Right);
+
+        assert!(frames[0].starts_with(">|...\\....\n|.-.\\.....\n"));
+        assert_eq!(
+            frames.last().unwrap(),
+            r#">|<<<\....
+|v-.\^....
+.v...|->>>
+.v...v^.|.
+.v...v^...
+.v...v^..\
+.v../2\\..
+<->-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+"#
+        );
+
+        assert_eq!(
+            Day16.frames(".|x\n"),
+            Some(Err("Invalid cell 'x' at (2, 0)".to_string()))
+        );
+        assert_eq!(Day16.frames(".\n"), Some(Ok(vec![">\n".to_string()])));
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "46");
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "51");
+    }
+}
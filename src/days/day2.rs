@@ -1,4 +1,9 @@
-use aoc2023::{input_filename, read_input};
+use std::hint::black_box;
+
+use crate::{
+    strings::{lines, split_label},
+    Answer, Solution,
+};
 
 #[derive(Debug)]
 pub enum C {
@@ -25,87 +30,83 @@ impl C {
 #[derive(Debug)]
 struct Game {
     num: u32,
-    cubes: Vec<Vec<C>>,
+    /// Every handful of every draw.
+    cubes: Vec<C>,
 }
 
-fn part1(data: &str) -> String {
-    let games = data
-        .lines()
-        .filter(|s| !s.is_empty())
-        .filter_map(|line| {
-            let (game, cubes) = line.split_once(':').expect("Find a :");
-            let game_num = game
-                .split_once(' ')
-                .unwrap()
-                .1
+fn parse(data: &str) -> Vec<Game> {
+    lines(data)
+        .map(|line| {
+            let (game, cubes) = split_label(line).expect("Find a :");
+            let num = game
+                .trim_start_matches("Game ")
                 .parse()
                 .expect("game number");
             let cubes = cubes
                 .split(';')
-                .map(|hand| hand.split(',').map(C::from).collect::<Vec<C>>())
-                .collect::<Vec<Vec<C>>>();
+                .flat_map(|hand| hand.split(','))
+                .map(C::from)
+                .collect::<Vec<C>>();
+            Game { num, cubes }
+        })
+        .collect()
+}
 
-            // filter invalid games
-            if cubes.iter().flatten().any(|cude| match cude {
+fn part1(data: &str) -> String {
+    parse(data)
+        .iter()
+        // filter invalid games
+        .filter(|game| {
+            !game.cubes.iter().any(|cude| match cude {
                 C::Blue(num) => *num > 14,
                 C::Red(num) => *num > 12,
                 C::Green(num) => *num > 13,
-            }) {
-                None
-            } else {
-                Some(Game {
-                    num: game_num,
-                    cubes,
-                })
-            }
+            })
         })
-        .collect::<Vec<Game>>();
-
-    games.iter().fold(0, |acc, game| acc + game.num).to_string()
+        .fold(0, |acc, game| acc + game.num)
+        .to_string()
 }
 
 fn part2(data: &str) -> String {
-    data.lines()
-        .filter(|s| !s.is_empty())
-        .map(|line| {
-            let (_game, cubes) = line.split_once(':').expect("Find a :");
-
-            let cubes = cubes
-                .split(';')
-                .map(|hand| hand.split(',').map(C::from).collect::<Vec<C>>())
-                .collect::<Vec<Vec<C>>>();
-
+    parse(data)
+        .iter()
+        .map(|game| {
             let mut blue = 0;
             let mut red = 0;
             let mut green = 0;
 
             // find the highest cubes per colour
-            cubes.iter().flatten().for_each(|cude| match cude {
+            game.cubes.iter().for_each(|cude| match cude {
                 C::Blue(num) => blue = blue.max(*num),
                 C::Red(num) => red = red.max(*num),
                 C::Green(num) => green = green.max(*num),
             });
 
-            let ans = u32::from(blue) * u32::from(red) * u32::from(green);
-            ans
+            u32::from(blue) * u32::from(red) * u32::from(green)
         })
         .sum::<u32>()
         .to_string()
 }
 
-fn main() {
-    let data = read_input(&format!("./input_{}.txt", input_filename(file!())));
+pub struct Day2;
 
-    let numbers = part1(&data);
-    println!("Part1: {numbers}");
+impl Solution for Day2 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(parse(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(part1(input))
+    }
 
-    let numbers = part2(&data);
-    println!("Part2: {numbers}");
+    fn part2(&self, input: &str) -> Answer {
+        Ok(part2(input))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2};
+    use super::{part1, part2};
 
     const SAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
@@ -0,0 +1,139 @@
+use std::{collections::BTreeMap, hint::black_box};
+
+use crate::{grid::Grid, Answer, Solution};
+
+#[derive(Debug)]
+struct NumPos {
+    y: usize,
+    xs: usize,
+    xe: usize,
+}
+
+#[derive(Debug)]
+struct Num {
+    value: u16,
+    pos: NumPos,
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// All numbers in the schematic, row by row.
+fn find_numbers(grid: &Grid<char>) -> Vec<Num> {
+    let mut numbers = Vec::<Num>::new();
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut obj: Option<Num> = None;
+        for (x, c) in row.iter().enumerate() {
+            if let Some(val) = c.to_digit(10) {
+                let val = val as u16;
+                if let Some(obj) = &mut obj {
+                    obj.value = obj.value * 10 + val;
+                    obj.pos.xe = x;
+                } else {
+                    obj = Some(Num {
+                        value: val,
+                        pos: NumPos { y, xs: x, xe: x },
+                    })
+                }
+            } else if let Some(num) = obj.take() {
+                numbers.push(num);
+            }
+        }
+        numbers.extend(obj);
+    }
+
+    numbers
+}
+
+/// Positions of the symbols touching any digit of `num`.
+fn adjacent_symbols(grid: &Grid<char>, num: &Num) -> Vec<(usize, usize)> {
+    let mut symbols = (num.pos.xs..=num.pos.xe)
+        .flat_map(|x| grid.neighbours8(x, num.pos.y))
+        .filter(|&pos| is_symbol(grid[pos]))
+        .collect::<Vec<(usize, usize)>>();
+    symbols.sort_unstable();
+    symbols.dedup();
+    symbols
+}
+
+fn part1(data: &str) -> Answer {
+    let grid = data.parse::<Grid<char>>()?;
+
+    let sum = find_numbers(&grid)
+        .iter()
+        .filter(|num| !adjacent_symbols(&grid, num).is_empty())
+        .map(|num| u32::from(num.value))
+        .sum::<u32>();
+
+    Ok(sum.to_string())
+}
+
+fn part2(data: &str) -> Answer {
+    let grid = data.parse::<Grid<char>>()?;
+
+    let mut gears = BTreeMap::<(usize, usize), Vec<u16>>::new();
+    for num in find_numbers(&grid) {
+        for pos in adjacent_symbols(&grid, &num) {
+            if grid[pos] == '*' {
+                gears.entry(pos).or_default().push(num.value);
+            }
+        }
+    }
+
+    let sum = gears
+        .values()
+        .filter_map(|numbers| {
+            if numbers.len() >= 2 {
+                Some(numbers.iter().map(|v| u32::from(*v)).product::<u32>())
+            } else {
+                None
+            }
+        })
+        .sum::<u32>();
+
+    Ok(sum.to_string())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(input.parse::<Grid<char>>());
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    const SAMPLE: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "4361");
+    }
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "467835");
+    }
+}
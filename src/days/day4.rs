@@ -0,0 +1,91 @@
+use std::hint::black_box;
+
+use crate::{
+    strings::{lines, numbers, split_label},
+    Answer, Solution,
+};
+
+/// How many of its numbers every card has won.
+fn parse(data: &str) -> Vec<usize> {
+    lines(data)
+        .map(|line| {
+            let (_card, rest) = split_label(line).unwrap();
+            let (lhs, rhs) = rest.split_once('|').unwrap();
+
+            let left = numbers::<u8>(lhs).unwrap();
+            numbers::<u8>(rhs)
+                .unwrap()
+                .into_iter()
+                .filter(|rhs| left.contains(rhs))
+                .count()
+        })
+        .collect()
+}
+
+fn part1(data: &str) -> String {
+    let mut score = 0;
+
+    for sum in parse(data) {
+        if sum != 0 {
+            score += 2_usize.pow(sum as u32 - 1);
+        }
+    }
+    score.to_string()
+}
+
+fn part2(data: &str) -> String {
+    let scores = parse(data);
+
+    let mut instances = vec![1_usize; scores.len()];
+
+    scores.iter().enumerate().for_each(|(idx, n)| {
+        let x = instances[idx];
+        for idx in idx + 1..idx + n + 1 {
+            if let Some(times) = instances.get_mut(idx) {
+                *times += x;
+            } else {
+                break;
+            }
+        }
+    });
+
+    instances.iter().sum::<usize>().to_string()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(parse(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(part1(input))
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part1, part2};
+
+    const SAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn example_1() {
+        assert_eq!(&part1(SAMPLE), "13");
+    }
+    #[test]
+    fn example_2() {
+        assert_eq!(&part2(SAMPLE), "30");
+    }
+}
@@ -0,0 +1,462 @@
+use std::{fmt, hint::black_box, ops::Range};
+
+use crate::{
+    strings::{numbers, split_label},
+    Answer, Solution,
+};
+
+#[derive(Debug)]
+struct R {
+    des: u64,
+    src: u64,
+    len: u64,
+}
+
+/// Map `ranges` through one stage of the almanac.
+///
+/// Every range is split at the `src` boundaries of the stage, the parts that
+/// fall inside an `R` are shifted to `des`, the rest keeps its numbers.
+fn map_ranges(items: &[R], ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+    let mut out = Vec::new();
+    let mut todo = ranges.to_vec();
+
+    while let Some(range) = todo.pop() {
+        if range.is_empty() {
+            continue;
+        }
+
+        let hit = items.iter().find_map(|r| {
+            let start = range.start.max(r.src);
+            let end = range.end.min(r.src.saturating_add(r.len));
+            (start < end).then_some((r, start, end))
+        });
+
+        match hit {
+            Some((r, start, end)) => {
+                out.push(r.des + (start - r.src)..r.des.saturating_add(end - r.src));
+                if range.start < start {
+                    todo.push(range.start..start);
+                }
+                if end < range.end {
+                    todo.push(end..range.end);
+                }
+            }
+            None => out.push(range),
+        }
+    }
+
+    out
+}
+
+/// One `from-to-to map:` block of the almanac.
+#[derive(Debug)]
+struct Stage {
+    from: String,
+    to: String,
+    items: Vec<R>,
+}
+
+impl Stage {
+    /// `name` is the header without ` map:`, e.g. "seed-to-soil".
+    fn is(&self, name: &str) -> bool {
+        name.split_once("-to-") == Some((self.from.as_str(), self.to.as_str()))
+    }
+
+    /// The first range containing `num` wins, numbers outside all ranges stay.
+    fn map(&self, num: u64) -> u64 {
+        self.items
+            .iter()
+            .find_map(|r| r.forward(num))
+            .unwrap_or(num)
+    }
+
+    /// Every number that this stage maps to `num`.
+    fn preimages(&self, num: u64) -> impl Iterator<Item = u64> + '_ {
+        self.items
+            .iter()
+            .filter_map(move |r| r.backward(num))
+            .chain(std::iter::once(num))
+            .filter(move |c| self.map(*c) == num)
+    }
+}
+
+/// The parsed almanac: the seeds and every stage in order.
+pub struct Game {
+    seeds: Vec<u64>,
+    soil: Vec<Stage>,
+}
+
+impl R {
+    fn forward(&self, num: u64) -> Option<u64> {
+        let offset = num.checked_sub(self.src).filter(|d| *d < self.len)?;
+        self.des.checked_add(offset)
+    }
+
+    fn backward(&self, num: u64) -> Option<u64> {
+        let offset = num.checked_sub(self.des).filter(|d| *d < self.len)?;
+        self.src.checked_add(offset)
+    }
+}
+
+impl Game {
+    /// The location of `seed`.
+    pub fn find(&self, seed: u64) -> u64 {
+        self.forward(seed, self.soil.len())
+    }
+
+    /// Index of the stage called `name`, e.g. "seed-to-soil".
+    fn stage(&self, name: &str) -> Option<usize> {
+        self.soil.iter().position(|s| s.is(name))
+    }
+
+    /// Map `num` through the first `stages` maps.
+    fn forward(&self, num: u64, stages: usize) -> u64 {
+        self.soil[..stages]
+            .iter()
+            .fold(num, |num, stage| stage.map(num))
+    }
+
+    /// A number that the first `stages` maps take to `num`, if there is one.
+    fn backward(&self, num: u64, stages: usize) -> Option<u64> {
+        let Some(stages) = stages.checked_sub(1) else {
+            return Some(num);
+        };
+        self.soil[stages]
+            .preimages(num)
+            .find_map(|c| self.backward(c, stages))
+    }
+
+    /// Map a seed up to and including the stage called `stage`.
+    pub fn find_until(&self, seed: u64, stage: &str) -> Option<u64> {
+        Some(self.forward(seed, self.stage(stage)? + 1))
+    }
+
+    /// A seed that ends up at `location`, `None` when no seed does.
+    pub fn find_reverse(&self, location: u64) -> Option<u64> {
+        self.backward(location, self.soil.len())
+    }
+
+    /// A seed that ends up at `num` after the stage called `stage`.
+    pub fn find_reverse_from(&self, num: u64, stage: &str) -> Option<u64> {
+        self.backward(num, self.stage(stage)? + 1)
+    }
+
+    /// Map whole seed ranges to the location ranges they end up in.
+    fn find_ranges(&self, seeds: &[Range<u64>]) -> Vec<Range<u64>> {
+        self.soil.iter().fold(seeds.to_vec(), |ranges, stage| {
+            map_ranges(&stage.items, &ranges)
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AlmanacError {
+    /// The input does not start with a `seeds: ...` line.
+    MissingSeeds,
+    /// No `from-to-to map:` block after the seeds.
+    NoStages,
+    /// `line` (1-based) is not a valid `from-to-to map:` header.
+    InvalidHeader { line: usize },
+    /// `line` is not a list of numbers of the expected length.
+    InvalidNumbers { line: usize },
+    /// The stage at `line` maps from `found`, the previous stage ended in `expected`.
+    BrokenChain {
+        line: usize,
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for AlmanacError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlmanacError::MissingSeeds => write!(f, "Missing seeds line"),
+            AlmanacError::NoStages => write!(f, "Almanac has no maps"),
+            AlmanacError::InvalidHeader { line } => write!(f, "Line {line}: invalid map header"),
+            AlmanacError::InvalidNumbers { line } => write!(f, "Line {line}: invalid numbers"),
+            AlmanacError::BrokenChain {
+                line,
+                expected,
+                found,
+            } => write!(f, "Line {line}: map from {found:?}, expected {expected:?}"),
+        }
+    }
+}
+
+impl std::error::Error for AlmanacError {}
+
+/// `seed-to-soil map` into `("seed", "soil")`.
+fn parse_header(header: &str) -> Option<(&str, &str)> {
+    let (from, to) = header.strip_suffix(" map")?.split_once("-to-")?;
+    (!from.is_empty() && !to.is_empty()).then_some((from, to))
+}
+
+fn parse_range(line: &str) -> Option<R> {
+    match numbers::<u64>(line).ok()?[..] {
+        [des, src, len] => Some(R { des, src, len }),
+        _ => None,
+    }
+}
+
+pub fn process(input: &str) -> Result<Game, AlmanacError> {
+    let mut rows = input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty());
+
+    let (line, first) = rows.next().ok_or(AlmanacError::MissingSeeds)?;
+    let seeds = match split_label(first) {
+        Some(("seeds", seeds)) => {
+            numbers(seeds).map_err(|_| AlmanacError::InvalidNumbers { line })?
+        }
+        _ => return Err(AlmanacError::MissingSeeds),
+    };
+
+    let mut soil: Vec<Stage> = Vec::new();
+    for (line, row) in rows {
+        if let Some(header) = row.strip_suffix(':') {
+            let (from, to) = parse_header(header).ok_or(AlmanacError::InvalidHeader { line })?;
+            let expected = soil.last().map_or("seed", |s| s.to.as_str());
+            if from != expected {
+                return Err(AlmanacError::BrokenChain {
+                    line,
+                    expected: expected.to_string(),
+                    found: from.to_string(),
+                });
+            }
+            soil.push(Stage {
+                from: from.to_string(),
+                to: to.to_string(),
+                items: Vec::new(),
+            });
+        } else {
+            let stage = soil
+                .last_mut()
+                .ok_or(AlmanacError::InvalidHeader { line })?;
+            let r = parse_range(row).ok_or(AlmanacError::InvalidNumbers { line })?;
+            stage.items.push(r);
+        }
+    }
+
+    if soil.is_empty() {
+        return Err(AlmanacError::NoStages);
+    }
+
+    Ok(Game { seeds, soil })
+}
+
+fn part1(input: &str) -> Answer {
+    let g = process(input)?;
+
+    let min = g.seeds.iter().map(|n| g.find(*n)).min().ok_or("No seeds")?;
+    Ok(min.to_string())
+}
+
+fn part2(input: &str) -> Answer {
+    let g = process(input)?;
+
+    let seeds = g
+        .seeds
+        .chunks_exact(2)
+        .map(|seeds| seeds[0]..seeds[0] + seeds[1])
+        .collect::<Vec<Range<u64>>>();
+
+    let min = g
+        .find_ranges(&seeds)
+        .iter()
+        .map(|r| r.start)
+        .min()
+        .ok_or("No seeds")?;
+    Ok(min.to_string())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_ranges, part1, part2, process, AlmanacError, R};
+
+    const SAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+
+";
+
+    #[test]
+    fn example_p1() {
+        let g = process(SAMPLE).unwrap();
+
+        assert_eq!(g.find(79), 82);
+        assert_eq!(g.find(14), 43);
+        assert_eq!(g.find(55), 86);
+        assert_eq!(g.find(13), 35);
+    }
+
+    #[test]
+    fn reverse() {
+        let g = process(SAMPLE).unwrap();
+
+        for seed in [79, 14, 55, 13] {
+            assert_eq!(g.find_reverse(g.find(seed)), Some(seed));
+        }
+        assert_eq!(g.find_reverse(46), Some(82));
+
+        assert_eq!(g.find_until(79, "seed-to-soil"), Some(81));
+        assert_eq!(g.find_until(79, "water-to-light"), Some(74));
+        assert_eq!(g.find_until(79, "humidity-to-location"), Some(82));
+        assert_eq!(g.find_until(79, "seed-to-gold"), None);
+        assert_eq!(g.find_reverse_from(74, "water-to-light"), Some(79));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn ranges() {
+        let seed_to_soil = [
+            R {
+                des: 50,
+                src: 98,
+                len: 2,
+            },
+            R {
+                des: 52,
+                src: 50,
+                len: 48,
+            },
+        ];
+
+        assert_eq!(map_ranges(&seed_to_soil, &[79..93]), vec![81..95]);
+        assert_eq!(map_ranges(&seed_to_soil, &[]), vec![]);
+
+        let mut split = map_ranges(&seed_to_soil, &[45..55, 97..101]);
+        split.sort_by_key(|r| r.start);
+        assert_eq!(split, vec![45..50, 50..52, 52..57, 99..100, 100..101]);
+
+        // Ranges that reach the largest number must not overflow.
+        let top = [R {
+            des: 0,
+            src: u64::MAX - 1,
+            len: 2,
+        }];
+        let mut split = map_ranges(&top, &[u64::MAX - 3..u64::MAX]);
+        split.sort_by_key(|r| r.start);
+        assert_eq!(split, vec![0..1, u64::MAX - 3..u64::MAX - 1]);
+
+        let g = process(SAMPLE).unwrap();
+        assert_eq!(g.find_ranges(&[82..83]), vec![46..47]);
+
+        let found = g.find_ranges(&[79..93, 55..68]);
+        assert_eq!(found.iter().map(|r| r.end - r.start).sum::<u64>(), 14 + 13);
+        assert_eq!(found.iter().map(|r| r.start).min(), Some(46));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let g = process("seeds: 1 2\nseed-to-soil map:\n5 1 1\n").unwrap();
+        assert_eq!(g.find(1), 5);
+        assert_eq!(g.find_reverse(5), Some(1));
+        assert_eq!(g.find_reverse(1), None);
+        assert_eq!(g.find_reverse(2), Some(2));
+
+        // The first range wins, so 8 only comes from itself, not from 1.
+        let g = process("seeds: 1\nseed-to-soil map:\n5 1 1\n8 1 1\n").unwrap();
+        assert_eq!(g.find_reverse(8), Some(8));
+        // 7 is reached from both 3 and 7.
+        let g = process("seeds: 1\nseed-to-soil map:\n7 3 1\n").unwrap();
+        assert_eq!(g.find_reverse(7).map(|s| g.find(s)), Some(7));
+        assert_eq!(g.find_reverse(3), None);
+
+        // A range that ends right at the largest number.
+        let g = process("seeds: 1\n\nseed-to-soil map:\n0 18446744073709551615 2\n").unwrap();
+        assert_eq!(g.find(u64::MAX), 0);
+        assert_eq!(g.find(1), 1);
+        assert_eq!(g.find_reverse(0), Some(u64::MAX));
+        assert_eq!(
+            part1("seeds: 1\n\nseed-to-soil map:\n0 18446744073709551615 2\n").unwrap(),
+            "1"
+        );
+
+        assert_eq!(process("").err(), Some(AlmanacError::MissingSeeds));
+        assert_eq!(
+            process("seeds: 1 2\n\n").err(),
+            Some(AlmanacError::NoStages)
+        );
+        assert_eq!(
+            process("seeds: 1 x\n").err(),
+            Some(AlmanacError::InvalidNumbers { line: 1 })
+        );
+        assert_eq!(
+            process("seeds: 1\n\nseed-to-soil map:\n1 2\n").err(),
+            Some(AlmanacError::InvalidNumbers { line: 4 })
+        );
+        assert_eq!(
+            process("seeds: 1\n\nseed to soil:\n1 2 3\n").err(),
+            Some(AlmanacError::InvalidHeader { line: 3 })
+        );
+        assert_eq!(
+            process("seeds: 1\n1 2 3\n").err(),
+            Some(AlmanacError::InvalidHeader { line: 2 })
+        );
+        assert_eq!(
+            process("seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n").err(),
+            Some(AlmanacError::BrokenChain {
+                line: 6,
+                expected: "soil".to_string(),
+                found: "water".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "35");
+    }
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "46");
+    }
+}
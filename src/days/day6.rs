@@ -0,0 +1,112 @@
+use std::hint::black_box;
+
+use crate::{
+    strings::{lines, numbers, split_label},
+    Answer, Solution,
+};
+
+fn part1(input: &[R]) -> String {
+    input
+        .iter()
+        .map(|r| {
+            (0..r.t)
+                .filter(|hold| {
+                    let time = r.t - hold;
+                    let dis = time * hold;
+                    dis > r.d
+                })
+                .count()
+        })
+        .product::<usize>()
+        .to_string()
+}
+
+fn part2(r: R) -> String {
+    (14..=r.t - 14)
+        .filter(|hold| {
+            let time = r.t - hold;
+            let dis = time * hold;
+            dis > r.d
+        })
+        .count()
+        .to_string()
+}
+
+fn parse(input: &str) -> Vec<R> {
+    let mut rows = lines(input).map(|line| split_label(line).expect("Find a :").1);
+    let times = numbers::<usize>(rows.next().expect("Time")).unwrap();
+    let distances = numbers::<usize>(rows.next().expect("Distance")).unwrap();
+
+    times
+        .into_iter()
+        .zip(distances)
+        .map(|(t, d)| R { t, d })
+        .collect()
+}
+
+/// Part 2 reads each line as one number, ignoring the spaces.
+fn parse_kerned(input: &str) -> R {
+    let mut rows = lines(input).map(|line| {
+        let (_, values) = split_label(line).expect("Find a :");
+        values.replace(' ', "").parse::<usize>().unwrap()
+    });
+
+    R {
+        t: rows.next().expect("Time"),
+        d: rows.next().expect("Distance"),
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(parse(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        Ok(part1(&parse(input)))
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        Ok(part2(parse_kerned(input)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct R {
+    t: usize,
+    d: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_kerned, part1, part2, R};
+
+    const SAMPLE_TEXT: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    const SAMPLE: [R; 3] = [R { t: 7, d: 9 }, R { t: 15, d: 40 }, R { t: 30, d: 200 }];
+
+    #[test]
+    fn example_1() {
+        assert_eq!(&part1(&SAMPLE), "288");
+    }
+    #[test]
+    fn example_2() {
+        assert_eq!(&part2(R { t: 71530, d: 200 }), "71503");
+    }
+
+    #[test]
+    fn process_data() {
+        assert_eq!(parse(SAMPLE_TEXT), SAMPLE);
+        assert_eq!(
+            parse_kerned(SAMPLE_TEXT),
+            R {
+                t: 71530,
+                d: 940200
+            }
+        );
+    }
+}
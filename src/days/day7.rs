@@ -0,0 +1,572 @@
+use std::hint::black_box;
+
+use core::fmt;
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{Answer, Solution};
+
+/// Strength of a card, its position in [`Rules::order`].
+#[derive(Debug, PartialEq, PartialOrd, Ord, Eq, Clone, Copy)]
+struct CV(pub u8);
+
+/// The most cards a deck can have, one histogram slot each.
+const MAX_CARDS: usize = 15;
+
+/// The most cards in a hand, 4 bits each in [`Card::strength`].
+const MAX_HAND: usize = 16;
+
+/// The rules of one variant of Camel Cards.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Rules {
+    /// Every card, from weakest to strongest.
+    order: String,
+    /// Cards that stand in for whichever card makes the best hand.
+    wild: String,
+    /// Cards in a hand.
+    hand_size: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RulesError {
+    /// More than [`MAX_CARDS`] cards in the order.
+    TooManyCards(usize),
+    /// A card listed twice in the order.
+    DuplicateCard(char),
+    /// A wild card that is not in the order.
+    UnknownWild(char),
+    /// Hands hold 1 to [`MAX_HAND`] cards.
+    HandSize(usize),
+    /// Not `<order>[:<wild>[:<hand size>]]`.
+    InvalidSpec(String),
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::TooManyCards(n) => {
+                write!(f, "Order has {n} cards, at most {MAX_CARDS} fit")
+            }
+            RulesError::DuplicateCard(c) => write!(f, "Card {c:?} listed twice"),
+            RulesError::UnknownWild(c) => write!(f, "Wild card {c:?} is not in the order"),
+            RulesError::HandSize(n) => write!(f, "Hands of {n} cards, expected 1 to {MAX_HAND}"),
+            RulesError::InvalidSpec(spec) => {
+                write!(f, "Expected <order>[:<wild>[:<hand size>]], got {spec:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+/// `<order>[:<wild>[:<hand size>]]`, e.g. `J23456789TQKA:J:5` for part 2.
+/// No wild cards and hands of 5 when left out.
+impl FromStr for Rules {
+    type Err = RulesError;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let invalid = || RulesError::InvalidSpec(spec.to_string());
+        let mut parts = spec.split(':');
+        let order = parts.next().filter(|o| !o.is_empty()).ok_or_else(invalid)?;
+        let wild = parts.next().unwrap_or("");
+        let hand_size = match parts.next() {
+            Some(n) => n.parse().map_err(|_| invalid())?,
+            None => 5,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        Self::new(order, wild, hand_size)
+    }
+}
+
+impl Rules {
+    /// `order` lists the cards from weakest to strongest, `wild` picks some
+    /// of them as jokers.
+    pub fn new(order: &str, wild: &str, hand_size: usize) -> Result<Self, RulesError> {
+        let cards = order.chars().count();
+        if cards > MAX_CARDS {
+            return Err(RulesError::TooManyCards(cards));
+        }
+        if let Some((_, c)) = order
+            .char_indices()
+            .find(|(idx, c)| order[..*idx].contains(*c))
+        {
+            return Err(RulesError::DuplicateCard(c));
+        }
+        if let Some(c) = wild.chars().find(|c| !order.contains(*c)) {
+            return Err(RulesError::UnknownWild(c));
+        }
+        if !(1..=MAX_HAND).contains(&hand_size) {
+            return Err(RulesError::HandSize(hand_size));
+        }
+
+        Ok(Self {
+            order: order.to_string(),
+            wild: wild.to_string(),
+            hand_size,
+        })
+    }
+
+    /// The rules of part 1.
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", "", 5).unwrap()
+    }
+
+    /// The rules of part 2, `J` is a weak joker.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", "J", 5).unwrap()
+    }
+
+    fn value(&self, card: char) -> Option<CV> {
+        let idx = self.order.chars().position(|c| c == card)?;
+        Some(CV(idx as u8))
+    }
+
+    fn is_wild(&self, card: char) -> bool {
+        self.wild.contains(card)
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct Card<'a> {
+    hand: &'a str,
+    /// The card values, 4 bits each, the first card in the highest bits.
+    strength: u64,
+    class: Class,
+}
+
+impl PartialOrd for Card<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Card<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.class.cmp(&other.class) {
+            core::cmp::Ordering::Equal => self.strength.cmp(&other.strength),
+            ord => ord,
+        }
+    }
+}
+
+impl fmt::Display for Card<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hand)
+    }
+}
+
+impl fmt::Debug for Card<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hand)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HandError {
+    /// A card that is not part of the rules.
+    UnknownCard(char),
+    /// `found` cards where the rules deal `expected`.
+    WrongSize { expected: usize, found: usize },
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandError::UnknownCard(c) => write!(f, "Unknown card {c:?}"),
+            HandError::WrongSize { expected, found } => {
+                write!(f, "Hand has {found} cards, expected {expected}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HandError {}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GameError {
+    /// The input has no hands.
+    NoHands,
+    /// `line` (1-based) is not a hand, a space and a bid.
+    InvalidLine { line: usize },
+    /// The hand on `line` does not fit the rules.
+    Hand { line: usize, error: HandError },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::NoHands => write!(f, "No hands"),
+            GameError::InvalidLine { line } => write!(f, "Line {line}: expected a hand and a bid"),
+            GameError::Hand { line, error } => write!(f, "Line {line}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl<'a> Card<'a> {
+    fn new(hand: &'a str, rules: &Rules) -> Result<Self, HandError> {
+        let found = hand.chars().count();
+        if found != rules.hand_size {
+            return Err(HandError::WrongSize {
+                expected: rules.hand_size,
+                found,
+            });
+        }
+
+        let mut strength = 0;
+        let mut counts = [0_u8; MAX_CARDS];
+        let mut wild = 0;
+        for c in hand.chars() {
+            let card = rules.value(c).ok_or(HandError::UnknownCard(c))?;
+            strength = strength << 4 | u64::from(card.0);
+            if rules.is_wild(c) {
+                wild += 1;
+            } else {
+                counts[usize::from(card.0)] += 1;
+            }
+        }
+
+        let (mut first, mut second) = (0, 0);
+        for n in counts {
+            if n > first {
+                (first, second) = (n, first);
+            } else if n > second {
+                second = n;
+            }
+        }
+
+        // The wild cards all join the largest group.
+        Ok(Self {
+            hand,
+            strength,
+            class: Class::new(first + wild, second),
+        })
+    }
+}
+
+fn process<'a>(input: &'a str, rules: &Rules) -> Result<Vec<(Card<'a>, u16)>, GameError> {
+    let hands = input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line, row)| {
+            let (hand, bid) = row.split_once(' ').ok_or(GameError::InvalidLine { line })?;
+            let bid = bid.parse().map_err(|_| GameError::InvalidLine { line })?;
+            let card = Card::new(hand, rules).map_err(|error| GameError::Hand { line, error })?;
+            Ok((card, bid))
+        })
+        .collect::<Result<Vec<(Card, u16)>, GameError>>()?;
+
+    if hands.is_empty() {
+        return Err(GameError::NoHands);
+    }
+    Ok(hands)
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+enum Class {
+    Single = 1,
+    Pair,
+    TwoPair,
+    ThreeOfKind,
+    FullHouse,
+    FourOfKind,
+    FiveOfKind,
+}
+
+impl Class {
+    /// From the sizes of the two largest groups of equal cards.
+    fn new(first: u8, second: u8) -> Self {
+        match (first, second) {
+            (5.., _) => Class::FiveOfKind,
+            (4, _) => Class::FourOfKind,
+            (3, 2..) => Class::FullHouse,
+            (3, _) => Class::ThreeOfKind,
+            (2, 2..) => Class::TwoPair,
+            (2, _) => Class::Pair,
+            _ => Class::Single,
+        }
+    }
+}
+
+/// All hands with their bids, weakest first.
+fn ranking<'a>(input: &'a str, rules: &Rules) -> Result<Vec<(Card<'a>, u16)>, GameError> {
+    let mut hands = process(input, rules)?;
+    hands.sort_by(|l, r| l.0.cmp(&r.0));
+    Ok(hands)
+}
+
+/// Sum of bid times rank over all hands, under any `rules`.
+pub fn winnings(input: &str, rules: &Rules) -> Result<usize, GameError> {
+    Ok(ranking(input, rules)?
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, score))| (idx + 1) * usize::from(*score))
+        .sum::<usize>())
+}
+
+/// Every hand with its class, rank and winnings.
+fn report(input: &str, rules: &Rules) -> Result<String, GameError> {
+    let mut out = format!(
+        "{:>5}  {:<8} {:<12} {:>6} {:>9}\n",
+        "Rank", "Hand", "Class", "Bid", "Winnings"
+    );
+    let mut total = 0;
+
+    for (idx, (card, bid)) in ranking(input, rules)?.iter().enumerate() {
+        let won = (idx + 1) * usize::from(*bid);
+        total += won;
+        out.push_str(&format!(
+            "{:>5}  {:<8} {:<12} {bid:>6} {won:>9}\n",
+            idx + 1,
+            card.hand,
+            format!("{:?}", card.class),
+        ));
+    }
+
+    out.push_str(&format!("Total: {total}\n"));
+    Ok(out)
+}
+
+fn part1(input: &str) -> Answer {
+    Ok(winnings(input, &Rules::standard())?.to_string())
+}
+
+fn part2(input: &str) -> Answer {
+    Ok(winnings(input, &Rules::jokers())?.to_string())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input, &Rules::standard()));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+
+    fn report(&self, input: &str, arg: Option<&str>) -> Option<Result<String, String>> {
+        let titled = |title: &str, rules: &Rules| {
+            report(input, rules)
+                .map(|r| format!("{title}\n{r}"))
+                .map_err(|e| e.to_string())
+        };
+        let standard = || titled("Standard rules", &Rules::standard());
+        let jokers = || titled("Joker rules", &Rules::jokers());
+
+        Some(match arg {
+            None => standard().and_then(|s| Ok(format!("{s}\n{}", jokers()?))),
+            Some("standard") => standard(),
+            Some("jokers") => jokers(),
+            Some(spec) => spec
+                .parse::<Rules>()
+                .map_err(|e| e.to_string())
+                .and_then(|rules| titled(&format!("Rules {spec}"), &rules)),
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::Solution;
+
+    use super::{
+        part1, part2, process, report, winnings, Card, Class, Day7, GameError, HandError, Rules,
+        RulesError,
+    };
+
+    const SAMPLE: &str = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+
+";
+
+    #[test]
+    fn process_data() {
+        let rules = &Rules::standard();
+
+        let g = process(SAMPLE, rules);
+
+        assert_eq!(
+            g,
+            Ok(vec![
+                (Card::new("32T3K", rules).unwrap(), 765),
+                (Card::new("T55J5", rules).unwrap(), 684),
+                (Card::new("KK677", rules).unwrap(), 28),
+                (Card::new("KTJJT", rules).unwrap(), 220),
+                (Card::new("QQQJA", rules).unwrap(), 483)
+            ])
+        );
+
+        assert_eq!(Card::new("AAAAA", rules).unwrap().class, Class::FiveOfKind);
+        assert_eq!(Card::new("AA8AA", rules).unwrap().class, Class::FourOfKind);
+        assert_eq!(Card::new("23332", rules).unwrap().class, Class::FullHouse);
+        assert_eq!(Card::new("TTT98", rules).unwrap().class, Class::ThreeOfKind);
+        assert_eq!(Card::new("23432", rules).unwrap().class, Class::TwoPair);
+        assert_eq!(Card::new("32T3K", rules).unwrap().class, Class::Pair);
+        assert_eq!(Card::new("A23A4", rules).unwrap().class, Class::Pair);
+        assert_eq!(Card::new("23456", rules).unwrap().class, Class::Single);
+
+        let mut hands = g.unwrap();
+
+        hands.sort_by(|l, r| l.0.cmp(&r.0));
+        hands.reverse();
+
+        assert_eq!(
+            hands,
+            vec![
+                (Card::new("QQQJA", rules).unwrap(), 483),
+                (Card::new("T55J5", rules).unwrap(), 684),
+                (Card::new("KK677", rules).unwrap(), 28),
+                (Card::new("KTJJT", rules).unwrap(), 220),
+                (Card::new("32T3K", rules).unwrap(), 765),
+            ]
+        );
+    }
+
+    #[test]
+    fn process_data_p2() {
+        let rules = &Rules::jokers();
+        let mut hands = process(SAMPLE, rules).unwrap();
+
+        hands.sort_by(|l, r| l.0.cmp(&r.0));
+
+        assert_eq!(
+            hands,
+            vec![
+                (Card::new("32T3K", rules).unwrap(), 765),
+                (Card::new("KK677", rules).unwrap(), 28),
+                (Card::new("T55J5", rules).unwrap(), 684),
+                (Card::new("QQQJA", rules).unwrap(), 483),
+                (Card::new("KTJJT", rules).unwrap(), 220),
+            ]
+        );
+    }
+
+    #[test]
+    fn rules() {
+        let rules = Rules::jokers();
+        assert_eq!(Card::new("JJJJJ", &rules).unwrap().class, Class::FiveOfKind);
+        assert_eq!(
+            Card::new("2JJ34", &rules).unwrap().class,
+            Class::ThreeOfKind
+        );
+        assert_eq!(Card::new("22J33", &rules).unwrap().class, Class::FullHouse);
+        assert!(Card::new("JJJJJ", &rules).unwrap() < Card::new("22222", &rules).unwrap());
+        assert_eq!(
+            Card::new("2345", &rules),
+            Err(HandError::WrongSize {
+                expected: 5,
+                found: 4
+            })
+        );
+        assert_eq!(Card::new("2345X", &rules), Err(HandError::UnknownCard('X')));
+        assert_eq!(
+            process("22345 1\n\n2345X 1\n", &rules).err(),
+            Some(GameError::Hand {
+                line: 3,
+                error: HandError::UnknownCard('X')
+            })
+        );
+        assert_eq!(
+            process("22345 1\n22345\n", &rules).err(),
+            Some(GameError::InvalidLine { line: 2 })
+        );
+        assert_eq!(
+            process("22345 x\n", &rules).err(),
+            Some(GameError::InvalidLine { line: 1 })
+        );
+        assert_eq!(process("\n", &rules).err(), Some(GameError::NoHands));
+
+        // Both 2 and 3 wild, three card hands.
+        let rules = Rules::new("23AK", "23", 3).unwrap();
+        assert_eq!(Card::new("2AK", &rules).unwrap().class, Class::Pair);
+        assert_eq!(Card::new("32A", &rules).unwrap().class, Class::ThreeOfKind);
+        assert_eq!(Card::new("AKA", &rules).unwrap().class, Class::Pair);
+        assert_eq!(winnings("AKA 10\n2AK 1\n", &rules), Ok(21));
+
+        assert_eq!(
+            Rules::new("0123456789ABCDEF", "", 5),
+            Err(RulesError::TooManyCards(16))
+        );
+        let max = Rules::new("0123456789ABCDE", "E", 5).unwrap();
+        assert_eq!(Card::new("EEEED", &max).unwrap().class, Class::FiveOfKind);
+        assert_eq!(
+            Rules::new("2342", "", 5),
+            Err(RulesError::DuplicateCard('2'))
+        );
+        assert_eq!(Rules::new("234", "J", 5), Err(RulesError::UnknownWild('J')));
+        assert_eq!(Rules::new("234", "", 0), Err(RulesError::HandSize(0)));
+        assert_eq!(Rules::new("234", "", 17), Err(RulesError::HandSize(17)));
+        let long = Rules::new("23", "", 16).unwrap();
+        let (low, high) = ("2".repeat(16), "2".repeat(15) + "3");
+        assert!(Card::new(&low, &long).unwrap() < Card::new(&high, &long).unwrap());
+    }
+
+    #[test]
+    fn ranking_report() {
+        let r = report(SAMPLE, &Rules::jokers()).unwrap();
+        let lines = r.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 7);
+        assert!(lines[0].trim_start().starts_with("Rank  Hand"));
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<&str>>(),
+            vec!["1", "32T3K", "Pair", "765", "765"]
+        );
+        assert_eq!(
+            lines[5].split_whitespace().collect::<Vec<&str>>(),
+            vec!["5", "KTJJT", "FourOfKind", "220", "1100"]
+        );
+        assert_eq!(lines[6], "Total: 5905");
+
+        let custom = Day7
+            .report(SAMPLE, Some("23456789TQKAJ:J"))
+            .unwrap()
+            .unwrap();
+        assert!(custom.starts_with("Rules 23456789TQKAJ:J\n"));
+        assert!(custom.contains("KTJJT    FourOfKind"));
+        assert!(custom.ends_with("Total: 5905\n"));
+        assert_eq!(
+            Day7.report("2345X 1\n", None),
+            Some(Err("Line 1: Unknown card 'X'".to_string()))
+        );
+        assert_eq!(
+            Day7.report(SAMPLE, Some("AK:Q")),
+            Some(Err("Wild card 'Q' is not in the order".to_string()))
+        );
+    }
+
+    #[test]
+    fn rules_spec() {
+        assert_eq!("J23456789TQKA:J".parse(), Ok(Rules::jokers()));
+        assert_eq!("23456789TJQKA".parse(), Ok(Rules::standard()));
+        assert_eq!("23AK:23:3".parse(), Rules::new("23AK", "23", 3));
+        assert_eq!(
+            "23:2:x".parse::<Rules>(),
+            Err(RulesError::InvalidSpec("23:2:x".to_string()))
+        );
+        assert!(":J".parse::<Rules>().is_err());
+        assert!("23:2:3:4".parse::<Rules>().is_err());
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "6440");
+    }
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "5905");
+    }
+}
@@ -1,6 +1,6 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, hint::black_box};
 
-use aoc2023::{input_filename, read_input};
+use crate::{Answer, Solution};
 use num::Integer;
 use winnow::{
     self,
@@ -103,34 +103,40 @@ fn process(input: &str) -> PResult<Game<'_>> {
     Ok(Game { dir, elements: ele })
 }
 
-fn part1(input: &str) -> String {
-    let g = process(input).unwrap();
+fn part1(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
 
     let num = g.search("AAA");
 
-    num.to_string()
+    Ok(num.to_string())
 }
 
-fn part2(input: &str) -> String {
-    let g = process(input).unwrap();
+fn part2(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
 
     let num = g.search_p2();
 
-    num.to_string()
+    Ok(num.to_string())
 }
 
-fn main() {
-    let data = read_input(&format!("./input_{}.txt", input_filename(file!())));
+pub struct Day8;
 
-    let numbers = part1(&data);
-    println!("Part1: {numbers}");
+impl Solution for Day8 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
 
-    let numbers = part2(&data);
-    println!("Part2: {numbers}");
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
 }
 #[cfg(test)]
 mod tests {
-    use crate::{part1, part2, process, str_to_u32};
+    use super::{part1, part2, process, str_to_u32};
 
     const SAMPLE: &str = "RL
 
@@ -191,10 +197,10 @@ XXX = (XXX, XXX)
 
     #[test]
     fn example_1() {
-        assert_eq!(&part1(SAMPLE), "2");
+        assert_eq!(part1(SAMPLE).unwrap(), "2");
     }
     #[test]
     fn example_2() {
-        assert_eq!(&part2(SAMPLE_2), "6");
+        assert_eq!(part2(SAMPLE_2).unwrap(), "6");
     }
 }
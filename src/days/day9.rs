@@ -0,0 +1,391 @@
+use std::{fmt, hint::black_box};
+
+use crate::{Answer, Solution};
+use itertools::Itertools;
+use winnow::{
+    self,
+    ascii::{dec_int, line_ending},
+    combinator::{repeat, separated},
+    PResult, Parser,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Game {
+    rows: Vec<Vec<i64>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SequenceError {
+    /// No values at all.
+    Empty,
+    /// The differences never become all zero.
+    NotConverging,
+    /// An intermediate value does not fit.
+    Overflow,
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SequenceError::Empty => write!(f, "Empty sequence"),
+            SequenceError::NotConverging => write!(f, "Differences never reach zero"),
+            SequenceError::Overflow => write!(f, "Overflow while extrapolating"),
+        }
+    }
+}
+
+impl std::error::Error for SequenceError {}
+
+/// The finite differences of a sequence.
+///
+/// Row 0 is the sequence itself, every next row holds the differences of the
+/// row above, the last row is all zeros.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DifferenceTable {
+    rows: Vec<Vec<i64>>,
+}
+
+impl DifferenceTable {
+    pub fn new(seq: &[i64]) -> Result<Self, SequenceError> {
+        if seq.is_empty() {
+            return Err(SequenceError::Empty);
+        }
+
+        let mut rows = vec![seq.to_vec()];
+        loop {
+            let last = rows.last().unwrap();
+            if last.is_empty() {
+                return Err(SequenceError::NotConverging);
+            }
+            if last.iter().all(|n| *n == 0) {
+                break;
+            }
+
+            let diff = last
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| b.checked_sub(*a).ok_or(SequenceError::Overflow))
+                .collect::<Result<Vec<i64>, SequenceError>>()?;
+            rows.push(diff);
+        }
+
+        Ok(Self { rows })
+    }
+
+    /// The value `k` steps after the end of the sequence.
+    pub fn forward(&self, k: usize) -> Result<i64, SequenceError> {
+        let mut edge = self
+            .rows
+            .iter()
+            .map(|r| r[r.len() - 1])
+            .collect::<Vec<i64>>();
+        for _ in 0..k {
+            for i in (0..edge.len() - 1).rev() {
+                edge[i] = edge[i]
+                    .checked_add(edge[i + 1])
+                    .ok_or(SequenceError::Overflow)?;
+            }
+        }
+        Ok(edge[0])
+    }
+
+    /// The value `k` steps before the start of the sequence.
+    pub fn backward(&self, k: usize) -> Result<i64, SequenceError> {
+        let mut edge = self.rows.iter().map(|r| r[0]).collect::<Vec<i64>>();
+        for _ in 0..k {
+            for i in (0..edge.len() - 1).rev() {
+                edge[i] = edge[i]
+                    .checked_sub(edge[i + 1])
+                    .ok_or(SequenceError::Overflow)?;
+            }
+        }
+        Ok(edge[0])
+    }
+
+    pub fn next(&self) -> Result<i64, SequenceError> {
+        self.forward(1)
+    }
+
+    pub fn prev(&self) -> Result<i64, SequenceError> {
+        self.backward(1)
+    }
+}
+
+/// How many values of `seq` the closed form needs: the smallest `m` for
+/// which the `m`-th differences are all zero.
+///
+/// The differences are taken in place in one buffer, no table is kept. A
+/// sequence that never reaches zero within its own length does not converge,
+/// the same rule as [`DifferenceTable::new`].
+fn order(seq: &[i64]) -> Result<usize, SequenceError> {
+    let mut row = seq.to_vec();
+    for m in 0..seq.len() {
+        let len = seq.len() - m;
+        if row[..len].iter().all(|n| *n == 0) {
+            return Ok(m);
+        }
+        for i in 0..len - 1 {
+            row[i] = row[i + 1]
+                .checked_sub(row[i])
+                .ok_or(SequenceError::Overflow)?;
+        }
+    }
+    Err(SequenceError::NotConverging)
+}
+
+/// The value after `seq`, without building the difference table.
+///
+/// With Newton's forward differences the `m`-th difference of `m + 1` values
+/// is `sum((-1)^(m - i) * C(m, i) * y[i])`, which is zero when the sequence
+/// comes from a polynomial of degree below `m`. Solving that for `y[m]` gives
+/// the next value from the last `m` values and binomial coefficients alone,
+/// with `m` from [`order`] so the coefficients stay small.
+pub fn binomial_next(seq: &[i64]) -> Result<i64, SequenceError> {
+    if seq.is_empty() {
+        return Err(SequenceError::Empty);
+    }
+
+    let m = order(seq)?;
+    let tail = &seq[seq.len() - m..];
+    let n = m as i128;
+    // C(m, i)
+    let mut coef: i128 = 1;
+    let mut sum: i128 = 0;
+    for (i, v) in tail.iter().enumerate() {
+        let i = i as i128;
+        let term = coef
+            .checked_mul(*v as i128)
+            .ok_or(SequenceError::Overflow)?;
+        sum = if (n - 1 - i) % 2 == 0 {
+            sum.checked_add(term)
+        } else {
+            sum.checked_sub(term)
+        }
+        .ok_or(SequenceError::Overflow)?;
+        coef = coef.checked_mul(n - i).ok_or(SequenceError::Overflow)? / (i + 1);
+    }
+
+    i64::try_from(sum).map_err(|_| SequenceError::Overflow)
+}
+
+/// The value before `seq`, which is the value after it reversed.
+pub fn binomial_prev(seq: &[i64]) -> Result<i64, SequenceError> {
+    let rev = seq.iter().rev().copied().collect::<Vec<i64>>();
+    binomial_next(&rev)
+}
+
+/// How to extrapolate a row.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Method {
+    /// Build the whole [`DifferenceTable`].
+    Table,
+    /// Closed form, see [`binomial_next`].
+    Binomial,
+}
+
+impl Method {
+    pub fn next(self, seq: &[i64]) -> Result<i64, SequenceError> {
+        match self {
+            Method::Table => DifferenceTable::new(seq)?.next(),
+            Method::Binomial => binomial_next(seq),
+        }
+    }
+
+    pub fn prev(self, seq: &[i64]) -> Result<i64, SequenceError> {
+        match self {
+            Method::Table => DifferenceTable::new(seq)?.prev(),
+            Method::Binomial => binomial_prev(seq),
+        }
+    }
+}
+
+const METHOD: Method = Method::Table;
+
+fn parse_num(input: &mut &str) -> PResult<i64> {
+    dec_int.parse_next(input)
+}
+
+fn parse_line(input: &mut &str) -> PResult<Vec<i64>> {
+    let numbers = separated(1.., parse_num, " ").parse_next(input)?;
+    line_ending.parse_next(input)?;
+
+    Ok(numbers)
+}
+
+fn process(input: &str) -> PResult<Game> {
+    let mut input = input;
+    let input = &mut input;
+    let nums = repeat(1.., parse_line).parse_next(input)?;
+    // opt(line_ending).parse_next(input)?;
+    // eof.parse_next(input)?;
+    Ok(Game { rows: nums })
+}
+
+fn part1(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
+
+    let sum = g.rows.iter().try_fold(0_i64, |sum, row| {
+        sum.checked_add(METHOD.next(row)?)
+            .ok_or(SequenceError::Overflow)
+    })?;
+    Ok(sum.to_string())
+}
+
+fn part2(input: &str) -> Answer {
+    let g = process(input).map_err(|e| e.to_string())?;
+
+    let sum = g.rows.iter().try_fold(0_i64, |sum, row| {
+        sum.checked_add(METHOD.prev(row)?)
+            .ok_or(SequenceError::Overflow)
+    })?;
+    Ok(sum.to_string())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn parse(&self, input: &str) {
+        let _ = black_box(process(input));
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        part2(input)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use winnow::Parser;
+
+    use super::{
+        binomial_next, parse_line, part1, part2, process, DifferenceTable, Game, Method,
+        SequenceError,
+    };
+    const SAMPLE: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#;
+
+    #[test]
+    fn process_data() {
+        assert_eq!(
+            parse_line.parse_peek("0 3 6 9 12 15\r\n"),
+            Ok(("", vec![0, 3, 6, 9, 12, 15]))
+        );
+
+        assert_eq!(
+            parse_line.parse_peek("-1 2 4\r\n"),
+            Ok(("", vec![-1, 2, 4]))
+        );
+
+        let g = process(SAMPLE);
+
+        assert_eq!(
+            g,
+            Ok(Game {
+                rows: vec![
+                    vec![0, 3, 6, 9, 12, 15],
+                    vec![1, 3, 6, 10, 15, 21],
+                    vec![10, 13, 16, 21, 30, 45]
+                ]
+            })
+        )
+    }
+
+    #[test]
+    fn example_1() {
+        assert_eq!(part1(SAMPLE).unwrap(), "114");
+    }
+    #[test]
+    fn extrapolate() {
+        let t = DifferenceTable::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(t.next(), Ok(68));
+        assert_eq!(t.prev(), Ok(5));
+        assert_eq!(t.forward(0), Ok(45));
+        assert_eq!(t.forward(2), Ok(101));
+        assert_eq!(t.backward(0), Ok(10));
+        assert_eq!(t.backward(2), Ok(-4));
+
+        assert_eq!(
+            DifferenceTable::new(&[7]),
+            Err(SequenceError::NotConverging)
+        );
+        assert_eq!(DifferenceTable::new(&[]), Err(SequenceError::Empty));
+        assert_eq!(DifferenceTable::new(&[0]).unwrap().next(), Ok(0));
+        assert_eq!(DifferenceTable::new(&[4, 4]).unwrap().prev(), Ok(4));
+
+        assert_eq!(
+            DifferenceTable::new(&[i64::MIN, i64::MAX]),
+            Err(SequenceError::Overflow)
+        );
+        let t = DifferenceTable::new(&[i64::MAX - 2, i64::MAX - 1, i64::MAX]).unwrap();
+        assert_eq!(t.next(), Err(SequenceError::Overflow));
+        assert_eq!(t.prev(), Ok(i64::MAX - 3));
+        let t = DifferenceTable::new(&[i64::MIN + 1, i64::MIN + 2, i64::MIN + 3]).unwrap();
+        assert_eq!(t.prev(), Ok(i64::MIN));
+        assert_eq!(t.backward(2), Err(SequenceError::Overflow));
+    }
+
+    #[test]
+    fn binomial() {
+        let g = process(SAMPLE).unwrap();
+        for row in &g.rows {
+            assert_eq!(Method::Binomial.next(row), Method::Table.next(row));
+            assert_eq!(Method::Binomial.prev(row), Method::Table.prev(row));
+        }
+
+        // A long cubic only needs its last four values.
+        let cubic = (0..200)
+            .map(|x: i64| x * x * x - 7 * x + 3)
+            .collect::<Vec<i64>>();
+        assert_eq!(
+            Method::Binomial.next(&cubic),
+            Ok(200 * 200 * 200 - 7 * 200 + 3)
+        );
+        assert_eq!(Method::Binomial.prev(&cubic), Ok(-1 + 7 + 3));
+        assert_eq!(binomial_next(&vec![1; 200]), Ok(1));
+        assert_eq!(binomial_next(&[]), Err(SequenceError::Empty));
+
+        let cases: [&[i64]; 9] = [
+            &cubic,
+            &[0],
+            &[7],
+            &[1, 2],
+            &[4, 4],
+            &[1, 2, 4, 8, 16],
+            &[1; 200],
+            &[i64::MIN, i64::MAX],
+            &[i64::MAX - 2, i64::MAX - 1, i64::MAX],
+        ];
+        for seq in cases {
+            assert_eq!(Method::Binomial.next(seq), Method::Table.next(seq));
+            assert_eq!(Method::Binomial.prev(seq), Method::Table.prev(seq));
+        }
+
+        assert_eq!(
+            Method::Binomial.next(&[7]),
+            Err(SequenceError::NotConverging)
+        );
+        assert_eq!(
+            Method::Binomial.next(&[1, 2]),
+            Err(SequenceError::NotConverging)
+        );
+        assert_eq!(Method::Binomial.next(&[0]), Ok(0));
+        assert_eq!(
+            Method::Binomial.next(&[i64::MIN, i64::MAX]),
+            Err(SequenceError::Overflow)
+        );
+    }
+
+    #[test]
+    fn example_2() {
+        assert_eq!(part2(SAMPLE).unwrap(), "2");
+        assert_eq!(
+            part2("1 2 4 8 16\n").unwrap_err().to_string(),
+            SequenceError::NotConverging.to_string()
+        );
+    }
+}
@@ -0,0 +1,36 @@
+pub mod day1;
+pub mod day10;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::Solution;
+
+/// Every solved day, in puzzle order.
+pub static DAYS: &[(u8, &dyn Solution)] = &[
+    (1, &day1::Day1),
+    (2, &day2::Day2),
+    (3, &day3::Day3),
+    (4, &day4::Day4),
+    (5, &day5::Day5),
+    (6, &day6::Day6),
+    (7, &day7::Day7),
+    (8, &day8::Day8),
+    (9, &day9::Day9),
+    (10, &day10::Day10),
+    (14, &day14::Day14),
+    (15, &day15::Day15),
+    (16, &day16::Day16),
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    DAYS.iter().find(|(d, _)| *d == day).map(|(_, s)| *s)
+}
@@ -0,0 +1,261 @@
+use std::ops::{Add, Not, Sub};
+
+/// The four orthogonal directions, `Up` is towards row 0.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+
+    /// `(dx, dy)` of one step in this direction.
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Dir4::Up => Point::new(0, -1),
+            Dir4::Down => Point::new(0, 1),
+            Dir4::Left => Point::new(-1, 0),
+            Dir4::Right => Point::new(1, 0),
+        }
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Left,
+            Dir4::Left => Dir4::Down,
+            Dir4::Down => Dir4::Right,
+            Dir4::Right => Dir4::Up,
+        }
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Right,
+            Dir4::Right => Dir4::Down,
+            Dir4::Down => Dir4::Left,
+            Dir4::Left => Dir4::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Down,
+            Dir4::Down => Dir4::Up,
+            Dir4::Left => Dir4::Right,
+            Dir4::Right => Dir4::Left,
+        }
+    }
+}
+
+impl Not for Dir4 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.reverse()
+    }
+}
+
+/// The eight compass directions, clockwise from `N` (towards row 0).
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    /// `(dx, dy)` of one step in this direction.
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Dir8::N => Point::new(0, -1),
+            Dir8::NE => Point::new(1, -1),
+            Dir8::E => Point::new(1, 0),
+            Dir8::SE => Point::new(1, 1),
+            Dir8::S => Point::new(0, 1),
+            Dir8::SW => Point::new(-1, 1),
+            Dir8::W => Point::new(-1, 0),
+            Dir8::NW => Point::new(-1, -1),
+        }
+    }
+
+    /// Eighth turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Eighth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl Not for Dir8 {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.reverse()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::N,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+            Dir4::Right => Dir8::E,
+        }
+    }
+}
+
+/// Position on a board, `x` grows to the right and `y` grows downwards.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = usize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Point<usize> {
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// `self + delta`, or `None` when either coordinate would go below zero.
+    pub fn checked_add_signed(self, delta: Point<isize>) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(delta.x)?,
+            self.y.checked_add_signed(delta.y)?,
+        ))
+    }
+
+    /// One step towards `dir`, staying inside `0..bounds.x` and `0..bounds.y`.
+    pub fn step(self, dir: impl Into<Dir8>, bounds: Self) -> Option<Self> {
+        let p = self.checked_add_signed(dir.into().delta())?;
+        (p.x < bounds.x && p.y < bounds.y).then_some(p)
+    }
+
+    pub fn signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+}
+
+impl Point<isize> {
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// One step towards `dir`, without any bounds.
+    pub fn offset(self, dir: impl Into<Dir8>) -> Self {
+        self + dir.into().delta()
+    }
+
+    /// Back to an unsigned point, if both coordinates are non-negative.
+    pub fn unsigned(self) -> Option<Point<usize>> {
+        Some(Point::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geom::{Dir4, Dir8, Point};
+
+    #[test]
+    fn turns() {
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), !dir);
+            assert_eq!(!!dir, dir);
+        }
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+
+        for dir in Dir8::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.reverse().delta(), Point::new(0, 0) - dir.delta());
+        }
+        assert_eq!(Dir8::NW.turn_right(), Dir8::N);
+        assert_eq!(Dir8::N.turn_left(), Dir8::NW);
+        assert_eq!(Dir8::from(Dir4::Left).delta(), Dir4::Left.delta());
+    }
+
+    #[test]
+    fn points() {
+        let a = Point::new(1_usize, 5);
+        let b = Point::new(4_usize, 2);
+
+        assert_eq!(a + b, Point::new(5, 7));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.signed() - b.signed(), Point::new(-3, 3));
+        assert_eq!(Point::new(-3_isize, 3).manhattan(Point::new(0, 0)), 6);
+        assert_eq!(Point::new(-3_isize, 3).unsigned(), None);
+        assert_eq!(Point::new(3_isize, 3).offset(Dir8::NE), Point::new(4, 2));
+    }
+
+    #[test]
+    fn step() {
+        let bounds = Point::new(3, 2);
+
+        assert_eq!(Point::new(0, 0).step(Dir4::Up, bounds), None);
+        assert_eq!(Point::new(0, 0).step(Dir4::Left, bounds), None);
+        assert_eq!(
+            Point::new(0, 0).step(Dir4::Down, bounds),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(Point::new(2, 1).step(Dir4::Right, bounds), None);
+        assert_eq!(Point::new(2, 1).step(Dir4::Down, bounds), None);
+        assert_eq!(
+            Point::new(2, 1).step(Dir8::NW, bounds),
+            Some(Point::new(1, 0))
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::geom::{Dir4, Dir8, Point};
+
+/// Rectangular board stored row by row in one `Vec`, indexed by `(x, y)`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GridError {
+    /// No rows at all.
+    Empty,
+    /// Row `y` has `found` cells while the first row has `expected`.
+    Ragged {
+        y: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The character at `(x, y)` is not a valid cell, `y` counts every line
+    /// of the input, blank ones too.
+    InvalidCell { x: usize, y: usize, c: char },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "Grid has no rows"),
+            GridError::Ragged { y, expected, found } => {
+                write!(f, "Row {y} has {found} cells, expected {expected}")
+            }
+            GridError::InvalidCell { x, y, c } => write!(f, "Invalid cell {c:?} at ({x}, {y})"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Build a grid from nested rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().ok_or(GridError::Empty)?.len();
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged {
+                    y,
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// `(width, height)` as a point, for [`Point::step`].
+    pub fn bounds(&self) -> Point {
+        Point::new(self.width, self.height)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Position `(dx, dy)` away from `(x, y)`, if it is still on the grid.
+    pub fn neighbour(&self, x: usize, y: usize, dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        self.contains(x, y).then_some((x, y))
+    }
+
+    /// Orthogonal neighbours of `(x, y)` that are on the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let p = Point::new(x, y);
+        Dir4::ALL
+            .into_iter()
+            .filter_map(move |dir| p.step(dir, self.bounds()))
+            .map(|p| (p.x, p.y))
+    }
+
+    /// Orthogonal and diagonal neighbours of `(x, y)` that are on the grid,
+    /// clockwise from north.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let p = Point::new(x, y);
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| p.step(dir, self.bounds()))
+            .map(|p| (p.x, p.y))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Every row, also for a grid of zero width.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} outside of grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All cells with their `(x, y)` position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| ((idx % width, idx / width), cell))
+    }
+
+    /// Position of the first cell, row by row, matching `pred`.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let idx = self.cells.iter().position(pred)?;
+        Some((idx % self.width, idx / self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.rebuild(self.height, self.width, |x, y| (y, h - 1 - x))
+    }
+
+    /// Rotate a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.rebuild(self.height, self.width, |x, y| (w - 1 - y, x))
+    }
+
+    /// New `width` x `height` grid where `(x, y)` is taken from `src(x, y)` in `self`.
+    fn rebuild(
+        &self,
+        width: usize,
+        height: usize,
+        src: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[src(x, y)].clone());
+            }
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({x}, {y}) outside of grid");
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({x}, {y}) outside of grid");
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        &self[(p.x, p.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        &mut self[(p.x, p.y)]
+    }
+}
+
+/// Parse one cell per character, one row per line. Blank lines are skipped.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // Number the lines before skipping blank ones, so `y` of an invalid
+        // cell is its line in `input`.
+        let rows = input
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| T::try_from(c).map_err(|_| GridError::InvalidCell { x, y, c }))
+                    .collect::<Result<Vec<T>, GridError>>()
+            })
+            .collect::<Result<Vec<Vec<T>>, GridError>>()?;
+
+        Self::from_rows(rows)
+    }
+}
+
+impl<T: Copy + Into<char>> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let s = row.iter().map(|&cell| cell.into()).collect::<String>();
+            writeln!(f, "{s}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Grid, GridError};
+
+    const SAMPLE: &str = "abc\r\ndef\r\n\r\n";
+
+    #[test]
+    fn parse() {
+        let g = SAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[(2, 0)], 'c');
+        assert_eq!(g.get(0, 1), Some(&'d'));
+        assert_eq!(g.get(3, 1), None);
+        assert_eq!(g.to_string(), "abc\ndef\n");
+
+        assert_eq!(
+            "ab\nc\n".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                y: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!("\n\n".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert!("12\n3x\n".parse::<Grid<u8>>().is_ok());
+        assert_eq!(
+            "ab\na€\n".parse::<Grid<u8>>(),
+            Err(GridError::InvalidCell {
+                x: 1, y: 1, c: '€'
+            })
+        );
+        assert_eq!(
+            "\n..\n\n.x\n".parse::<Grid<u8>>(),
+            Ok(Grid::from_rows(vec![vec![b'.'; 2], vec![b'.', b'x']]).unwrap())
+        );
+        assert_eq!(
+            "\r\n..\r\n\r\n.€\r\n".parse::<Grid<u8>>(),
+            Err(GridError::InvalidCell {
+                x: 1, y: 3, c: '€'
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let g = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(g.neighbour(0, 0, -1, 0), None);
+        assert_eq!(g.neighbour(0, 0, 1, 1), Some((1, 1)));
+        assert_eq!(g.neighbour(2, 1, 1, 0), None);
+
+        assert_eq!(
+            g.neighbours4(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            g.neighbours8(1, 0).collect::<Vec<_>>(),
+            vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(g.row(1), &['d', 'e', 'f']);
+        assert_eq!(g.rows().count(), 2);
+        assert_eq!(g.column(1).collect::<String>(), "be");
+        assert_eq!(
+            g.columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(g.position(|c| *c == 'e'), Some((1, 1)));
+
+        let empty = Grid::new(0, 2, 'a');
+        assert_eq!(empty.rows().collect::<Vec<_>>(), vec![&[] as &[char]; 2]);
+        assert_eq!(empty.columns().count(), 0);
+        assert_eq!(empty.to_string(), "\n\n");
+
+        let narrow = Grid::<char>::from_rows(vec![vec![], vec![]]).unwrap();
+        assert_eq!((narrow.width(), narrow.rows().count()), (0, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 outside of grid")]
+    fn column_bounds() {
+        let g = SAMPLE.parse::<Grid<char>>().unwrap();
+        let _ = g.column(3);
+    }
+
+    #[test]
+    fn transform() {
+        let g = SAMPLE.parse::<Grid<char>>().unwrap();
+
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(g.rotate_cw().rotate_ccw(), g);
+        assert_eq!(g.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), g);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    fmt,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable holding the directory with the `input_dayN.txt` files.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    Missing(PathBuf),
+    /// Any other failure while opening or reading the input.
+    Io(PathBuf, io::Error),
+    /// The input is not valid UTF-8.
+    NotUtf8(PathBuf),
+    /// The input has no content at all.
+    Empty(PathBuf),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "Input {} not found", path.display()),
+            InputError::Io(path, e) => write!(f, "Could not read {}: {e}", path.display()),
+            InputError::NotUtf8(path) => write!(f, "Input {} is not UTF-8", path.display()),
+            InputError::Empty(path) => write!(f, "Input {} is empty", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Read the whole input from `filename`, or from stdin when it is `-`.
+pub fn read_input(filename: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = filename.as_ref();
+    let io_err = |e: io::Error| {
+        if e.kind() == io::ErrorKind::NotFound {
+            InputError::Missing(path.to_path_buf())
+        } else {
+            InputError::Io(path.to_path_buf(), e)
+        }
+    };
+
+    let mut buf = Vec::<u8>::new();
+
+    if path == Path::new("-") {
+        io::stdin().read_to_end(&mut buf).map_err(io_err)?;
+    } else {
+        let mut f = std::fs::File::open(path).map_err(io_err)?;
+        if let Ok(m) = f.metadata() {
+            buf.reserve(m.len() as usize);
+        }
+        f.read_to_end(&mut buf).map_err(io_err)?;
+    }
+
+    if buf.is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+
+    String::from_utf8(buf).map_err(|_| InputError::NotUtf8(path.to_path_buf()))
+}
+
+/// Directory with the puzzle inputs.
+///
+/// An explicit `dir` wins over [`INPUT_DIR_ENV`], which wins over the current
+/// directory.
+pub fn input_dir(dir: Option<&Path>) -> PathBuf {
+    match dir {
+        Some(dir) => dir.to_path_buf(),
+        None => std::env::var_os(INPUT_DIR_ENV)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(".")),
+    }
+}
+
+/// Path of the input file for `day` inside `dir`.
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("input_day{day}.txt"))
+}
+
+/// File stem of a source path, `src/bin/day7.rs` gives `day7`.
+pub fn input_filename(source_filename: &str) -> &str {
+    let fl = source_filename
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(source_filename);
+    fl.split_once('.').map_or(fl, |(stem, _)| stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::input::{input_filename, input_path, read_input, InputError};
+
+    #[test]
+    fn filename() {
+        assert_eq!(input_filename("src/bin/day7.rs"), "day7");
+        assert_eq!(input_filename("src\\bin\\day7.rs"), "day7");
+        assert_eq!(input_filename("day7"), "day7");
+        assert_eq!(input_filename("./a/day7"), "day7");
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(
+            input_path(Path::new("inputs"), 7),
+            Path::new("inputs/input_day7.txt")
+        );
+    }
+
+    #[test]
+    fn read_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc2023_input_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.txt");
+        assert!(matches!(read_input(&missing), Err(InputError::Missing(p)) if p == missing));
+
+        let empty = dir.join("empty.txt");
+        std::fs::write(&empty, "").unwrap();
+        assert!(matches!(read_input(&empty), Err(InputError::Empty(_))));
+
+        let binary = dir.join("binary.txt");
+        std::fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+        assert!(matches!(read_input(&binary), Err(InputError::NotUtf8(_))));
+
+        let good = dir.join("good.txt");
+        std::fs::write(&good, "1 2 3\n").unwrap();
+        assert_eq!(read_input(&good).unwrap(), "1 2 3\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+//! The HASHMAP of 2023 day 15: 256 boxes of lenses, picked by HASH.
+
+use std::{borrow::Borrow, fmt};
+
+/// The HASH algorithm: add each byte, multiply by 17, keep the low 8 bits.
+pub fn hash(bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(0_u8, |acc, c| acc.wrapping_add(*c).wrapping_mul(17))
+}
+
+/// Map keeping the entries of every box in insertion order.
+///
+/// A replaced value keeps its slot, a removed one closes the gap.
+#[derive(Clone, PartialEq, Eq)]
+pub struct LensMap<K, V> {
+    boxes: Vec<Vec<(K, V)>>,
+}
+
+impl<K, V> Default for LensMap<K, V> {
+    fn default() -> Self {
+        Self {
+            boxes: (0..256).map(|_| Vec::new()).collect(),
+        }
+    }
+}
+
+impl<K: AsRef<[u8]> + Eq, V> LensMap<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn bucket<Q: AsRef<[u8]> + ?Sized>(key: &Q) -> usize {
+        usize::from(hash(key.as_ref()))
+    }
+
+    /// Add `key` at the back of its box, or replace its value in place.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let b = &mut self.boxes[Self::bucket(&key)];
+        match b.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, v)) => Some(std::mem::replace(v, value)),
+            None => {
+                b.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        let b = &mut self.boxes[Self::bucket(key)];
+        let idx = b.iter().position(|(k, _)| k.borrow() == key)?;
+        Some(b.remove(idx).1)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        self.boxes[Self::bucket(key)]
+            .iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, v)| v)
+    }
+}
+
+impl<K, V> LensMap<K, V> {
+    pub fn len(&self) -> usize {
+        self.boxes.iter().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.iter().all(Vec::is_empty)
+    }
+
+    /// The non-empty boxes with their number.
+    pub fn boxes(&self) -> impl Iterator<Item = (usize, &[(K, V)])> {
+        self.boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.is_empty())
+            .map(|(idx, b)| (idx, b.as_slice()))
+    }
+
+    /// All entries, box by box.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.boxes.iter().flatten().map(|(k, v)| (k, v))
+    }
+
+    /// Sum of box number times slot number times value, both counted from 1.
+    pub fn focusing_power(&self) -> usize
+    where
+        V: Copy + Into<usize>,
+    {
+        self.boxes()
+            .flat_map(|(box_idx, b)| {
+                b.iter()
+                    .enumerate()
+                    .map(move |(slot, (_, v))| (box_idx + 1) * (slot + 1) * (*v).into())
+            })
+            .sum()
+    }
+}
+
+/// The non-empty boxes as in the puzzle text, one `Box 3: [pc 4] [ot 9]` line
+/// each.
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for LensMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, b) in self.boxes() {
+            write!(f, "Box {idx}:")?;
+            for (k, v) in b {
+                write!(f, " [{k} {v}]")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Only the non-empty boxes, keyed by their number.
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for LensMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.boxes()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lens::{hash, LensMap};
+
+    #[test]
+    fn hashes() {
+        assert_eq!(hash(b"HASH"), 52);
+        assert_eq!(hash(b"rn"), 0);
+        assert_eq!(hash(b"qp"), 1);
+        assert_eq!(hash(b""), 0);
+    }
+
+    #[test]
+    fn map() {
+        let mut m = LensMap::new();
+        assert!(m.is_empty());
+
+        assert_eq!(m.insert("rn".to_string(), 1_u8), None);
+        assert_eq!(m.insert("cm".to_string(), 2), None);
+        assert_eq!(m.insert("rn".to_string(), 3), Some(1));
+        assert_eq!(m.get("rn"), Some(&3));
+        assert_eq!(m.len(), 2);
+        assert_eq!(format!("{m:?}"), r#"{0: [("rn", 3), ("cm", 2)]}"#);
+        assert_eq!(m.to_string(), "Box 0: [rn 3] [cm 2]\n");
+
+        assert_eq!(m.remove("rn"), Some(3));
+        assert_eq!(m.remove("rn"), None);
+        assert_eq!(m.iter().collect::<Vec<_>>(), vec![(&"cm".to_string(), &2)]);
+        assert_eq!(m.focusing_power(), 2);
+    }
+}
@@ -1,20 +1,34 @@
-use std::io::Read;
-
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod geom;
+pub mod grid;
+pub mod input;
+pub mod lens;
 pub mod strings;
 
-pub fn read_input(filename: &str) -> String {
-    let mut f = std::fs::File::open(filename).unwrap();
+pub use input::{input_dir, input_filename, input_path, read_input, InputError};
 
-    let m = f.metadata().unwrap().len() as usize;
+/// The answer to one part, or why the input could not be solved.
+pub type Answer = Result<String, Box<dyn std::error::Error>>;
 
-    let mut buf = Vec::<u8>::with_capacity(m);
+/// One day of the calendar, solving both parts from the raw puzzle input.
+pub trait Solution: Sync {
+    fn part1(&self, input: &str) -> Answer;
+    fn part2(&self, input: &str) -> Answer;
 
-    f.read_to_end(&mut buf).unwrap();
+    /// Only parse the input, so benchmarks can time it apart from solving.
+    fn parse(&self, _input: &str) {}
 
-    String::from_utf8(buf).unwrap()
-}
+    /// Frames of an animation of the solution, for days that have one.
+    fn frames(&self, _input: &str) -> Option<Result<Vec<String>, String>> {
+        None
+    }
 
-pub fn input_filename(source_filename: &str) -> &str {
-    let (_, fl) = source_filename.rsplit_once('/').unwrap();
-    fl.split_once('.').unwrap().0
+    /// Details beyond the answers for days that have them, `arg` picks what
+    /// to show and its meaning is up to the day.
+    fn report(&self, _input: &str, _arg: Option<&str>) -> Option<Result<String, String>> {
+        None
+    }
 }
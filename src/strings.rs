@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+/// Non-empty lines of `input`, with any trailing `\r` removed.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.is_empty())
+}
+
+/// Parse every whitespace-separated word of `input` as a number.
+///
+/// Works for any `FromStr` type, so both `u64` and `i64` style lists parse
+/// the same way: `"-1 2 4"` gives `[-1, 2, 4]`.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>, T::Err> {
+    input.split_whitespace().map(str::parse).collect()
+}
+
+/// Split a `label: values` line at the first `:`, trimming both halves.
+pub fn split_label(line: &str) -> Option<(&str, &str)> {
+    let (label, values) = line.split_once(':')?;
+    Some((label.trim(), values.trim()))
+}
+
+/// Blocks of lines separated by one or more blank lines.
+///
+/// None of the days uses it: day 5 parses line by line so it can report
+/// errors with their line. It stays for inputs made of blank-line separated
+/// sections, where the line numbers are not needed.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks { rest: input }
+}
+
+/// Iterator returned by [`blocks`].
+///
+/// Each block is returned without its trailing line ending, inner lines are
+/// left untouched.
+#[derive(Debug, Clone)]
+pub struct Blocks<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut start = None;
+        let mut end = 0;
+        let mut consumed = self.rest.len();
+        let mut pos = 0;
+
+        for line in self.rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if start.is_some() {
+                    consumed = pos + line.len();
+                    break;
+                }
+            } else {
+                start.get_or_insert(pos);
+                end = pos + content.len();
+            }
+            pos += line.len();
+        }
+
+        let block = &self.rest[start?..end];
+        self.rest = &self.rest[consumed..];
+        Some(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::strings::{blocks, lines, numbers, split_label};
+
+    #[test]
+    fn split_lines() {
+        assert_eq!(
+            lines("ab\r\ncd\n\nef").collect::<Vec<&str>>(),
+            vec!["ab", "cd", "ef"]
+        );
+        assert_eq!(lines("\r\n\n").count(), 0);
+    }
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(numbers::<u64>(" 79 14  55 13 "), Ok(vec![79, 14, 55, 13]));
+        assert_eq!(numbers::<i64>("-1 2 -4"), Ok(vec![-1, 2, -4]));
+        assert_eq!(numbers::<u8>(""), Ok(vec![]));
+        assert!(numbers::<u8>("1 x 3").is_err());
+        assert!(numbers::<u8>("-1").is_err());
+    }
+
+    #[test]
+    fn label() {
+        assert_eq!(
+            split_label("Card 1: 41 48 | 83 86"),
+            Some(("Card 1", "41 48 | 83 86"))
+        );
+        assert_eq!(split_label("seeds:79 14"), Some(("seeds", "79 14")));
+        assert_eq!(split_label("no label"), None);
+    }
+
+    #[test]
+    fn split_blocks() {
+        let input = "seeds: 79\r\n\r\na map:\r\n1 2 3\r\n4 5 6\r\n\r\n\r\nb map:\n7 8 9\n\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<&str>>(),
+            vec!["seeds: 79", "a map:\r\n1 2 3\r\n4 5 6", "b map:\n7 8 9"]
+        );
+
+        assert_eq!(blocks("\n\n  \n").count(), 0);
+        assert_eq!(blocks("#.#\n.#.").collect::<Vec<&str>>(), vec!["#.#\n.#."]);
+    }
+}